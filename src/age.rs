@@ -1,14 +1,15 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::sim_time::SimTime;
 
-#[derive(Debug, Component, Default)]
+#[derive(Debug, Clone, Component, Default, Serialize, Deserialize)]
 pub struct Age {
     pub duration_alive: std::time::Duration,
 }
 
-pub fn age_up_system(time: Res<Time>, sim_time: Res<SimTime>, mut query: Query<&mut Age>) {
+pub fn age_up_system(sim_time: Res<SimTime>, mut query: Query<&mut Age>) {
     for mut age in &mut query {
-        age.duration_alive += sim_time.delta(&time);
+        age.duration_alive += sim_time.delta();
     }
 }
@@ -8,5 +8,7 @@ pub const DEFAULT_VOTING_CENTER: &str = "sprites/buildings/voting_center.png";
 pub const DEFAULT_MINT_SPRITE_PATH: &str = "sprites/buildings/mint.png";
 pub const DEFAULT_MONEY_HOLE_SPRITE_PATH: &str = "sprites/buildings/money_hole.png";
 pub const DEFAULT_HOUSE_SPRITE_PATH: &str = "sprites/buildings/house.png";
+pub const DEFAULT_GRANARY_SPRITE_PATH: &str = "sprites/buildings/granary.png";
 
 pub const FOOD_CONFIG_FILE: &str = "config/foods.food_collection.toml";
+pub const DEFAULT_SCENARIO_FILE: &str = "config/default.scenario.toml";
@@ -0,0 +1,279 @@
+#![feature(generic_const_exprs)]
+
+use std::{fs::File, path::PathBuf, process};
+
+use strum::IntoEnumIterator;
+use voties::tally::{
+    ballot::{self, TallyError},
+    import::{self, Format, ImportedElection},
+    voting_methods::VoteCount,
+    ElectionType, ElectionTypeResult,
+};
+
+#[derive(Debug)]
+struct Args {
+    path: PathBuf,
+    format: Format,
+    // What score ballots are out of
+    max_score: Option<u32>,
+    methods: Vec<ElectionType>,
+    // Only used when the file doesn't say
+    seats: usize,
+    json: bool,
+}
+
+// The short names people know the methods by
+const ALIASES: [(&str, ElectionType); 9] = [
+    ("fptp", ElectionType::FirstPastThePost),
+    ("plurality", ElectionType::FirstPastThePost),
+    ("irv", ElectionType::Preferential),
+    ("av", ElectionType::Preferential),
+    ("mj", ElectionType::UsualJudgment),
+    ("rp", ElectionType::RankedPairs),
+    ("tideman", ElectionType::RankedPairs),
+    ("stv", ElectionType::SingleTransferableVote),
+    ("pav", ElectionType::ProportionalApproval),
+];
+
+fn method_from_name(name: &str) -> Result<ElectionType, String> {
+    let key = name.replace([' ', '-', '_'], "");
+    ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(&key))
+        .map(|(_, election_type)| *election_type)
+        .or_else(|| {
+            ElectionType::iter()
+                .find(|election_type| format!("{:?}", election_type).eq_ignore_ascii_case(&key))
+        })
+        .ok_or_else(|| {
+            let methods = ElectionType::iter()
+                .map(|election_type| format!("{:?}", election_type))
+                .chain(ALIASES.iter().map(|(alias, _)| alias.to_string()))
+                .collect::<Vec<_>>();
+            format!(
+                "There is no method called {}, try one of {}",
+                name,
+                methods.join(", ")
+            )
+        })
+}
+
+impl Args {
+    /// Accepts `--format <blt|ranked|approval|score>`, `--method <name>` as many times as wanted,
+    /// `--seats <N>`, `--max-score <N>` for score files and `--json` along with the path to the ballots.
+    fn from_args(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut path: Option<PathBuf> = None;
+        let mut format = None;
+        let mut max_score = None;
+        let mut methods = vec![];
+        let mut seats = 1;
+        let mut json = false;
+
+        let mut args = args.skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" => {
+                    format = Some(
+                        args.next()
+                            .and_then(|value| Format::from_name(&value))
+                            .ok_or("--format requires one of blt, ranked, approval or score")?,
+                    );
+                }
+                "--method" => {
+                    let name = args.next().ok_or("--method requires a method name")?;
+                    methods.push(method_from_name(&name)?);
+                }
+                "--seats" => {
+                    seats = args
+                        .next()
+                        .and_then(|value| value.parse().ok())
+                        .filter(|seats| *seats > 0)
+                        .ok_or("--seats requires a whole number of seats above 0")?;
+                }
+                "--max-score" => {
+                    max_score = Some(
+                        args.next()
+                            .and_then(|value| value.parse().ok())
+                            .filter(|max_score| *max_score > 0)
+                            .ok_or("--max-score requires a whole number above 0")?,
+                    );
+                }
+                "--json" => json = true,
+                _ => path = Some(arg.into()),
+            }
+        }
+
+        let path = path.ok_or("Pass the path to a ballot file")?;
+        // BLT files say what they are, everything else is taken to be a csv of rankings
+        let format = format.unwrap_or_else(|| match path.extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("blt") => Format::Blt,
+            _ => Format::RankedCsv,
+        });
+        if format == Format::ScoreCsv && max_score.is_none() {
+            return Err("Score files need --max-score to say what the scores are out of".into());
+        }
+        if methods.is_empty() {
+            methods = ElectionType::iter().collect();
+        }
+
+        Ok(Self {
+            path,
+            format,
+            max_score,
+            methods,
+            seats,
+            json,
+        })
+    }
+}
+
+fn vote_counts_to_string(vote_counts: &[VoteCount<String>]) -> String {
+    vote_counts
+        .iter()
+        .map(|count| format!("{} {}", count.option, count.votes))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Every round the method counted, for those that count in rounds
+fn rounds_to_string(result: &ElectionTypeResult<String>, candidates: &[String]) -> String {
+    let mut str = string_builder::Builder::default();
+    match result {
+        ElectionTypeResult::PreferentialResult(result) => {
+            for (i, round) in result.rounds.iter().enumerate() {
+                str.append(format!(
+                    "    Round {}: {}\n",
+                    i + 1,
+                    vote_counts_to_string(&round.vote_tally)
+                ));
+            }
+        }
+        ElectionTypeResult::BaldwinResult(result) => {
+            for (i, round) in result.rounds.iter().enumerate() {
+                str.append(format!(
+                    "    Round {}: {}\n",
+                    i + 1,
+                    vote_counts_to_string(&round.vote_tally)
+                ));
+            }
+        }
+        ElectionTypeResult::NansonResult(result) => {
+            for (i, round) in result.rounds.iter().enumerate() {
+                str.append(format!(
+                    "    Round {}: {}\n",
+                    i + 1,
+                    vote_counts_to_string(&round.vote_tally)
+                ));
+            }
+        }
+        ElectionTypeResult::CoombsResult(result) => {
+            for (i, round) in result.rounds.iter().enumerate() {
+                str.append(format!(
+                    "    Round {}: {} - last places: {}\n",
+                    i + 1,
+                    vote_counts_to_string(&round.vote_tally),
+                    vote_counts_to_string(&round.last_place_tally)
+                ));
+            }
+        }
+        ElectionTypeResult::SingleTransferableVoteResult(result) => {
+            str.append(format!("    Quota: {}\n", result.quota));
+            for (i, round) in result.rounds.iter().enumerate() {
+                str.append(format!(
+                    "    Round {}: {}\n",
+                    i + 1,
+                    round
+                        .vote_tally
+                        .iter()
+                        .map(|tally| format!(
+                            "{} {:.2}",
+                            candidates[tally.option_index], tally.votes
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+        ElectionTypeResult::StarResult(result) => {
+            str.append(format!(
+                "    Runoff: {} {}, {} {}\n",
+                candidates[result.runoff.a.option_index],
+                result.runoff.a.votes,
+                candidates[result.runoff.b.option_index],
+                result.runoff.b.votes
+            ));
+        }
+        _ => {}
+    }
+
+    str.string().unwrap()
+}
+
+fn main() {
+    let args = match Args::from_args(std::env::args()) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+
+    let file = match File::open(&args.path) {
+        Ok(file) => file,
+        Err(err) => {
+            eprintln!("Unable to open {}: {}", args.path.display(), err);
+            process::exit(1);
+        }
+    };
+    let ImportedElection {
+        title,
+        candidates,
+        seats,
+        ballots,
+    } = match import::read(file, args.format, args.seats, args.max_score) {
+        Ok(election) => election,
+        Err(err) => {
+            eprintln!("Unable to read {}: {}", args.path.display(), err);
+            process::exit(1);
+        }
+    };
+    if candidates.is_empty() || ballots.is_empty() {
+        eprintln!("{} has no candidates or no ballots", args.path.display());
+        process::exit(1);
+    }
+
+    let mut results = vec![];
+    for election_type in &args.methods {
+        match ballot::tally(*election_type, &candidates, &ballots, seats) {
+            Ok(result) => results.push(result),
+            Err(TallyError::NoValidBallots) => {
+                eprintln!("{} has no valid ballots", args.path.display());
+                process::exit(1);
+            }
+            Err(err) => eprintln!("Skipping {}, it {}", election_type.to_string(), err),
+        }
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+        return;
+    }
+
+    if let Some(title) = title {
+        println!("{}", title);
+    }
+    println!(
+        "{} candidates, {} ballots, {} seats",
+        candidates.len(),
+        ballots.len(),
+        seats
+    );
+    for result in &results {
+        println!(
+            "{}: {}",
+            result.get_type().to_string(),
+            result.winners_to_string()
+        );
+        print!("{}", rounds_to_string(result, &candidates));
+    }
+}
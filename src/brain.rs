@@ -2,13 +2,24 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 use bevy_enum_filter::prelude::*;
+use rand::Rng;
 
 use crate::{
-    elections::election::Election,
+    age::Age,
+    buildings::index::{BuildingIndex, BuildingKind},
+    elections::{
+        election::Election,
+        turnout::{self, Demographic, TurnoutFactors},
+        voter::Voter,
+    },
     energy::Energy,
     goals::{self, Goals},
     hunger::Stomach,
+    money::Wallet,
+    movement::MovementSpeed,
+    navigation::NavGrid,
     reproduction::Reproductive,
+    rng,
     shelter::RequiresHouse,
     sim_time::SimTime,
 };
@@ -16,16 +27,28 @@ use crate::{
 #[derive(Debug, Component, Default)]
 pub struct Brain;
 
-fn do_election_goal(
+fn do_election_goal<'a>(
     goal: &mut Goals,
     entity: Entity,
-    elections: &Query<(Entity, &Election)>,
+    rng: &mut impl Rng,
+    demographic: Demographic,
+    factors: impl Fn() -> TurnoutFactors<'a>,
+    elections: &mut Query<(Entity, &mut Election)>,
 ) -> bool {
-    for (election_entity, election) in elections {
-        if election.votes.contains_key(&entity) {
+    for (election_entity, mut election) in elections.iter_mut() {
+        if election.votes.contains_key(&entity) || election.abstained.contains(&entity) {
             continue;
         }
 
+        // Made up once per election, those who'll vote keep trying until they have
+        if !election.electorate.contains_key(&entity) {
+            election.electorate.insert(entity, demographic);
+            if !rng.gen_bool(turnout::chance_to_vote(&factors()) as f64) {
+                election.abstained.insert(entity);
+                continue;
+            }
+        }
+
         *goal = Goals::Vote(goals::vote::Vote::new(election_entity));
         return true;
     }
@@ -34,6 +57,9 @@ fn do_election_goal(
 }
 
 pub fn decide_system(
+    mut rng: ResMut<rng::Rng>,
+    building_index: Res<BuildingIndex>,
+    nav_grid: Res<NavGrid>,
     mut query: Query<
         (
             Entity,
@@ -42,13 +68,51 @@ pub fn decide_system(
             Option<&Stomach>,
             Option<&Reproductive>,
             Option<&RequiresHouse>,
+            &Transform,
+            &MovementSpeed,
+            &Voter,
+            &Age,
+            Option<&Wallet>,
         ),
         (With<Brain>, With<Enum!(goals::Goals::None)>),
     >,
-    elections: Query<(Entity, &Election)>,
+    mut elections: Query<(Entity, &mut Election)>,
 ) {
-    for (entity, mut goal, energy, stomach, reproductive, requires_house) in &mut query {
-        if do_election_goal(&mut goal, entity, &elections) {
+    for (
+        entity,
+        mut goal,
+        energy,
+        stomach,
+        reproductive,
+        requires_house,
+        transform,
+        speed,
+        voter,
+        age,
+        wallet,
+    ) in &mut query
+    {
+        let factors = || TurnoutFactors {
+            voter,
+            energy,
+            stomach,
+            travel_seconds: building_index
+                .nearest(&[BuildingKind::VotingCenter], transform.translation, |_| {
+                    true
+                })
+                .map(|voting_center| {
+                    nav_grid.travel_distance(transform.translation, voting_center.translation)
+                        / speed.0
+                }),
+        };
+        if do_election_goal(
+            &mut goal,
+            entity,
+            &mut rng.inner,
+            Demographic::new(age, requires_house),
+            factors,
+            &mut elections,
+        ) {
             continue;
         }
 
@@ -73,6 +137,14 @@ pub fn decide_system(
             }
         }
 
+        // Those short of what they want put by would rather work than wander
+        if let Some(wallet) = wallet {
+            if wallet.money < goals::work::savings_target(voter) {
+                *goal = Goals::Work(default());
+                continue;
+            }
+        }
+
         *goal = Goals::Wander(default());
     }
 }
@@ -2,11 +2,12 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 use bevy_enum_filter::EnumFilter;
+use serde::{Deserialize, Serialize};
 
-use crate::{hunger::FoodTemplate, reproduction::ReproductiveZoneBundle, upkeep};
+use crate::{hunger::FoodTemplate, money::Money, reproduction::ReproductiveZoneBundle, upkeep};
 
 use super::voting_center::VotingCenterBundle;
-use super::{house, money_hole};
+use super::{granary, house, money_hole};
 
 use super::farm::create_farm;
 
@@ -15,10 +16,10 @@ use super::mint;
 const PLOT_START_X: f32 = -300.0;
 const PLOT_START_Y: f32 = 200.0;
 const PLOT_END_X: f32 = 300.0;
-const PLOT_SIZE_X: f32 = 150.0;
-const PLOT_SIZE_Y: f32 = 150.0;
+pub const PLOT_SIZE_X: f32 = 150.0;
+pub const PLOT_SIZE_Y: f32 = 150.0;
 
-#[derive(Debug, Resource)]
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
 pub struct BuildingPlots {
     next_plot: Vec2,
     current_steps_in_spiral: i32,
@@ -101,7 +102,7 @@ impl BuildingPlots {
         result
     }
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub enum Building {
     VotingCenter,
     Farm(FoodTemplate),
@@ -109,6 +110,7 @@ pub enum Building {
     MoneyHole,
     Mint,
     House(i32),
+    Granary,
 }
 
 impl ToString for Building {
@@ -120,46 +122,69 @@ impl ToString for Building {
             Building::MoneyHole => "Money Hole".to_owned(),
             Building::Mint => "Mint".to_owned(),
             Building::House(_) => "House".to_owned(),
+            Building::Granary => "Granary".to_owned(),
         }
     }
 }
 
 impl Building {
+    pub fn construction_cost(&self) -> Money {
+        match self {
+            Building::VotingCenter => 2000.0,
+            Building::Farm(_) => 1000.0,
+            Building::ReproductiveZone => 1000.0,
+            Building::MoneyHole => 1500.0,
+            Building::Mint => 2500.0,
+            Building::House(dwellings) => *dwellings as Money * 250.0,
+            Building::Granary => 1200.0,
+        }
+    }
+
     pub fn build(
         &self,
         commands: &mut Commands,
         asset_server: &AssetServer,
         plots: &mut BuildingPlots,
         rng: &mut impl rand::Rng,
-    ) {
+    ) -> Entity {
         let location = plots.next();
 
-        match self {
-            Building::VotingCenter => {
-                commands.spawn(VotingCenterBundle::new(asset_server, location));
-            }
+        let entity = match self {
+            Building::VotingCenter => commands
+                .spawn(VotingCenterBundle::new(asset_server, location))
+                .id(),
             Building::Farm(produces) => {
-                create_farm(commands, asset_server, produces.clone(), location, rng);
-            }
-            Building::ReproductiveZone => {
-                commands.spawn(ReproductiveZoneBundle::new(asset_server, location));
+                create_farm(commands, asset_server, produces.clone(), location, rng)
             }
+            Building::ReproductiveZone => commands
+                .spawn(ReproductiveZoneBundle::new(asset_server, location))
+                .id(),
             Building::MoneyHole => {
                 let storage_capacity = rng.gen_range(
                     money_hole::MONEY_HOLE_CAPACITY_MIN..money_hole::MONEY_HOLE_CAPACITY_MAX,
                 );
-                money_hole::spawn(commands, asset_server, storage_capacity, location);
+                money_hole::spawn(commands, asset_server, storage_capacity, location)
             }
             Building::Mint => {
                 let mps = rng.gen_range(mint::MINT_MPS_MIN..mint::MINT_MPS_MAX);
                 let production_cycle =
                     rng.gen_range(Duration::from_secs(1)..Duration::from_secs(2));
-                mint::spawn(commands, asset_server, mps, production_cycle, location);
+                mint::spawn(commands, asset_server, mps, production_cycle, location)
             }
             Building::House(dwellings) => {
-                house::spawn(commands, asset_server, *dwellings, location);
+                house::spawn(commands, asset_server, *dwellings, location)
             }
-        }
+            Building::Granary => {
+                let capacity =
+                    rng.gen_range(granary::GRANARY_CAPACITY_MIN..granary::GRANARY_CAPACITY_MAX);
+                granary::spawn(commands, asset_server, capacity, location)
+            }
+        };
+
+        // Kept on the building so it can be rebuilt the same way when loading a save
+        commands.entity(entity).insert(self.clone());
+
+        entity
     }
 }
 
@@ -174,6 +199,8 @@ impl Building {
     enum_iterator::Sequence,
     Component,
     EnumFilter,
+    Serialize,
+    Deserialize,
 )]
 pub enum BuildingStatus {
     Operational,
@@ -0,0 +1,99 @@
+use std::{collections::VecDeque, fmt};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{elections::election::ElectionOption, money::Treasury, rng, tax::Tax};
+
+use super::building::{Building, BuildingPlots};
+
+// What happens when an election picks something the treasury can't pay for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConstructionPolicy {
+    // Wait in the construction queue until there is money
+    #[default]
+    Defer,
+    // Build the next ranked option instead
+    FallThrough,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ConstructionOutcome {
+    NothingToBuild,
+    Built(Building),
+    Queued(Building),
+    FellThrough(Building),
+    // A policy rather than a building won
+    Enacted { tax: Tax, rate: u32 },
+}
+
+impl fmt::Display for ConstructionOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConstructionOutcome::NothingToBuild => write!(f, "Nothing to build"),
+            ConstructionOutcome::Built(building) => write!(f, "Built {}", building.to_string()),
+            ConstructionOutcome::Queued(building) => write!(f, "Queued {}", building.to_string()),
+            ConstructionOutcome::FellThrough(building) => {
+                write!(f, "Couldn't afford {}", building.to_string())
+            }
+            ConstructionOutcome::Enacted { tax, rate } => {
+                write!(f, "Set {} to {}", tax, tax.rate_to_string(*rate))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Resource, Default, Serialize, Deserialize)]
+pub struct ConstructionQueue {
+    pub policy: ConstructionPolicy,
+    pub queued: VecDeque<Building>,
+}
+
+// Pays for and builds an election winner, anything unaffordable is handled by the policy
+pub fn construct(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    plots: &mut BuildingPlots,
+    rng: &mut impl rand::Rng,
+    treasury: &mut Treasury,
+    queue: &mut ConstructionQueue,
+    option: &ElectionOption,
+) -> ConstructionOutcome {
+    let building = match option.building() {
+        Some(building) => building,
+        None => return ConstructionOutcome::NothingToBuild,
+    };
+
+    // Queued buildings were voted for first so they get paid for first
+    if queue.queued.is_empty() && treasury.spend(building.construction_cost()) {
+        building.build(commands, asset_server, plots, rng);
+        return ConstructionOutcome::Built(building);
+    }
+
+    match queue.policy {
+        ConstructionPolicy::Defer => {
+            queue.queued.push_back(building.clone());
+            ConstructionOutcome::Queued(building)
+        }
+        ConstructionPolicy::FallThrough => ConstructionOutcome::FellThrough(building),
+    }
+}
+
+pub fn construction_queue_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut plots: ResMut<BuildingPlots>,
+    mut rng: ResMut<rng::Rng>,
+    mut treasury: ResMut<Treasury>,
+    mut queue: ResMut<ConstructionQueue>,
+) {
+    while let Some(building) = queue.queued.front() {
+        if !treasury.spend(building.construction_cost()) {
+            return;
+        }
+
+        info!("Building queued {}", building.to_string());
+        building.build(&mut commands, &asset_server, &mut plots, &mut rng.inner);
+        queue.queued.pop_front();
+    }
+}
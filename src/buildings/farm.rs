@@ -1,21 +1,55 @@
-use std::time::Duration;
+use std::{collections::VecDeque, time::Duration};
 
 use bevy::prelude::*;
 use bevy_enum_filter::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     assets, collision,
     hunger::{self, FoodTemplate},
+    money::{Money, Wallet},
     sim_time::SimTime,
     upkeep,
+    world_stats::WorldStats,
 };
 
-use super::building;
+use super::{
+    building,
+    granary::Granary,
+    workplace::{Job, Workplace},
+};
 
-#[derive(Component, Clone, Default)]
+// How far the price moves each batch
+const PRICE_STEP: f64 = 0.05;
+// How far from its base price the price can go either way, as a multiple of it
+const PRICE_SWING: f64 = 3.0;
+
+#[derive(Component, Clone, Default, Serialize, Deserialize)]
 pub struct Farm {
     pub surplus: i32,
     pub production_timer: Timer,
+    // What one serving costs right now
+    #[serde(default)]
+    pub price: Money,
+    // Servings sold since the last batch was made
+    #[serde(default)]
+    pub sold: i32,
+    // Unsold batches oldest first, surplus from before this was tracked never spoils
+    #[serde(default)]
+    pub stock: VecDeque<Batch>,
+    // Servings spoiled since the last batch was made
+    #[serde(default)]
+    pub spoiled: i32,
+}
+
+/// Servings made together, they all spoil together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Batch {
+    // Sim time it was made
+    pub made: Duration,
+    pub count: i32,
+    // In a granary, where it keeps
+    pub stored: bool,
 }
 
 impl Farm {
@@ -24,12 +58,83 @@ impl Farm {
             return false;
         }
         self.surplus -= 1;
+
+        // Oldest first so the least food goes off
+        if let Some(batch) = self.stock.front_mut() {
+            batch.count -= 1;
+            if batch.count <= 0 {
+                self.stock.pop_front();
+            }
+        }
+
         true
     }
 
+    pub fn stored(&self) -> i32 {
+        self.stock
+            .iter()
+            .filter(|batch| batch.stored)
+            .map(|batch| batch.count)
+            .sum()
+    }
+
+    /// Spoils or stores every batch past `shelf_life`, returning how many servings spoiled.
+    pub fn spoil(&mut self, now: Duration, shelf_life: Duration, granary_room: &mut i32) -> i32 {
+        let mut spoiled = 0;
+
+        for batch in self.stock.iter_mut() {
+            if batch.stored || now.saturating_sub(batch.made) < shelf_life {
+                continue;
+            }
+
+            let to_store = batch.count.min(*granary_room);
+            *granary_room -= to_store;
+
+            spoiled += batch.count - to_store;
+            batch.count = to_store;
+            batch.stored = true;
+        }
+
+        self.stock.retain(|batch| batch.count > 0);
+        self.surplus -= spoiled;
+        self.spoiled += spoiled;
+
+        spoiled
+    }
+
     pub fn has_surplus(&self) -> bool {
         self.surplus > 0
     }
+
+    pub fn can_afford(&self, wallet: &Wallet) -> bool {
+        wallet.money >= self.price
+    }
+
+    /// Sells one serving out of `wallet`, returning what was paid.
+    pub fn sell(&mut self, wallet: &mut Wallet) -> Option<Money> {
+        if !self.can_afford(wallet) || !self.take_food() {
+            return None;
+        }
+
+        wallet.money -= self.price;
+        self.sold += 1;
+        Some(self.price)
+    }
+
+    // Selling out means food is short, nothing selling or food going off means nobody wants it at this price
+    fn update_price(&mut self, base_price: Money) {
+        if self.surplus <= 0 {
+            self.price *= 1.0 + PRICE_STEP;
+        } else if self.sold == 0 || self.spoiled > 0 {
+            self.price *= 1.0 - PRICE_STEP;
+        }
+
+        self.price = self
+            .price
+            .clamp(base_price / PRICE_SWING, base_price * PRICE_SWING);
+        self.sold = 0;
+        self.spoiled = 0;
+    }
 }
 
 struct ProductionRange {
@@ -80,11 +185,23 @@ pub fn create_farm(
     produces: FoodTemplate,
     location: Vec2,
     rng: &mut impl rand::Rng,
-) {
-    let upkeep_cost = produces.upkeep_cost();
-
+) -> Entity {
     let production_range = get_production_time_range(produces.difficulty as u64);
     let production_time = rng.gen_range(production_range.min..production_range.max) / 2;
+
+    spawn(commands, asset_server, produces, production_time, location)
+}
+
+pub fn spawn(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    produces: FoodTemplate,
+    production_time: Duration,
+    location: Vec2,
+) -> Entity {
+    let upkeep_cost = produces.upkeep_cost();
+    let price = produces.get_food().base_price();
+
     let farm_name = format!(
         "{} Farm - {:.2}/s ${:.2}/s",
         produces.name,
@@ -101,6 +218,8 @@ pub fn create_farm(
                     production_time.as_secs_f32(),
                     TimerMode::Repeating,
                 ),
+                price,
+                ..default()
             },
             produces: produces.into(),
             sprite: SpriteBundle {
@@ -110,6 +229,7 @@ pub fn create_farm(
             },
             ..default()
         })
+        .insert(Workplace::new(Job::Farming))
         .id();
 
     let text_id = commands
@@ -131,22 +251,57 @@ pub fn create_farm(
         .id();
 
     commands.entity(farm_id).add_child(text_id);
+
+    farm_id
 }
 
 pub fn farms_make_food_system(
-    time: Res<Time>,
     sim_time: Res<SimTime>,
-    mut query: Query<&mut Farm, With<Enum!(building::BuildingStatus::Operational)>>,
+    mut world_stats: ResMut<WorldStats>,
+    mut query: Query<
+        (&mut Farm, &hunger::Food, Option<&Workplace>),
+        With<Enum!(building::BuildingStatus::Operational)>,
+    >,
 ) {
-    for mut farm in query.iter_mut() {
-        let new_surplus = farm
-            .production_timer
-            .tick(sim_time.delta(&time))
-            .times_finished_this_tick();
+    for (mut farm, food, workplace) in query.iter_mut() {
+        // Short staffed farms take longer to grow the same food
+        let delta = sim_time
+            .delta()
+            .mul_f32(workplace.map_or(1.0, |workplace| workplace.output_scale()));
+        let new_surplus = farm.production_timer.tick(delta).times_finished_this_tick();
 
         if new_surplus > 0 {
+            farm.update_price(food.base_price());
             // TODO make text float up
             farm.surplus += new_surplus as i32;
+            farm.stock.push_back(Batch {
+                made: sim_time.elapsed(),
+                count: new_surplus as i32,
+                stored: false,
+            });
+            world_stats.add_food_produced(new_surplus as usize);
+        }
+    }
+}
+
+pub fn spoil_food_system(
+    sim_time: Res<SimTime>,
+    mut world_stats: ResMut<WorldStats>,
+    granaries: Query<&Granary, With<Enum!(building::BuildingStatus::Operational)>>,
+    mut farms: Query<(&mut Farm, &hunger::Food)>,
+) {
+    let capacity = granaries
+        .iter()
+        .map(|granary| granary.capacity)
+        .sum::<i32>();
+    let stored = farms.iter().map(|(farm, _)| farm.stored()).sum::<i32>();
+    // Food already put by keeps its place even if a granary closes
+    let mut granary_room = (capacity - stored).max(0);
+
+    for (mut farm, food) in &mut farms {
+        let spoiled = farm.spoil(sim_time.elapsed(), food.shelf_life, &mut granary_room);
+        if spoiled > 0 {
+            world_stats.add_food_spoiled(spoiled as usize);
         }
     }
 }
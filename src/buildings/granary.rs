@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{assets, money::Money, upkeep};
+
+use super::building;
+
+// In servings
+pub const GRANARY_CAPACITY_MIN: i32 = 20;
+pub const GRANARY_CAPACITY_MAX: i32 = 60;
+
+/// Keeps food from the town's farms from going off, up to its capacity.
+#[derive(Component, Clone, Default, Serialize, Deserialize)]
+pub struct Granary {
+    pub capacity: i32,
+}
+
+#[derive(Component, Clone, Default)]
+pub struct GranaryText;
+
+#[derive(Bundle, Clone, Default)]
+pub struct GranaryTextBundle {
+    pub granary_text: GranaryText,
+    #[bundle]
+    pub text2d: Text2dBundle,
+}
+
+#[derive(Bundle, Clone, Default)]
+pub struct GranaryBundle {
+    pub granary: Granary,
+    pub building_status: building::BuildingStatus,
+    pub upkeep: upkeep::UpkeepCost,
+    #[bundle]
+    pub sprite: SpriteBundle,
+}
+
+pub fn spawn(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    capacity: i32,
+    location: Vec2,
+) -> Entity {
+    let upkeep_cost = capacity as Money / 5.0;
+
+    commands
+        .spawn(GranaryBundle {
+            granary: Granary { capacity },
+            upkeep: upkeep::UpkeepCost::new(upkeep_cost),
+            sprite: SpriteBundle {
+                texture: asset_server.load(crate::assets::DEFAULT_GRANARY_SPRITE_PATH),
+                transform: Transform::from_translation(Vec3::new(location.x, location.y, 0.0)),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(GranaryTextBundle {
+                text2d: Text2dBundle {
+                    text: Text::from_section(
+                        format!("Granary {} servings - ${:.2}/s", capacity, upkeep_cost),
+                        TextStyle {
+                            font: asset_server.load(assets::DEFAULT_FONT_PATH),
+                            font_size: 10.0,
+                            color: Color::BLACK,
+                        },
+                    ),
+                    transform: Transform::from_xyz(0.0, 60.0, 10.0),
+                    ..default()
+                },
+                ..default()
+            });
+        })
+        .id()
+}
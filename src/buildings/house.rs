@@ -1,11 +1,20 @@
 use bevy::{prelude::*, utils::HashSet};
 use bevy_enum_filter::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::{assets, buildings, collision, money::Money, shelter, upkeep};
+use crate::{
+    assets, buildings, collision,
+    money::Money,
+    save::{EntityRemap, RemapEntities},
+    shelter, upkeep,
+};
 
-use super::building;
+use super::{
+    building,
+    workplace::{Job, Workplace},
+};
 
-#[derive(Component, Clone, Default)]
+#[derive(Component, Clone, Default, Serialize, Deserialize)]
 pub struct House {
     pub occupied: HashSet<Entity>,
     pub dwellings: i32,
@@ -34,6 +43,18 @@ impl House {
     }
 }
 
+impl RemapEntities for House {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        let mut occupied = self.occupied.drain().collect::<Vec<_>>();
+        occupied.sort();
+
+        self.occupied = occupied
+            .into_iter()
+            .map(|occupant| remap.get(occupant))
+            .collect();
+    }
+}
+
 #[derive(Component, Clone, Default)]
 pub struct HouseText;
 
@@ -50,10 +71,15 @@ pub struct HouseBundle {
 pub const MIN_DWELLINGS: i32 = 3;
 pub const MAX_DWELLINGS: i32 = 10;
 
-pub fn spawn(commands: &mut Commands, asset_server: &AssetServer, dwellings: i32, location: Vec2) {
+pub fn spawn(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    dwellings: i32,
+    location: Vec2,
+) -> Entity {
     let upkeep_cost = (dwellings as Money * 100.0) / 60.0;
 
-    let id = commands
+    commands
         .spawn(HouseBundle {
             house: House {
                 dwellings,
@@ -82,7 +108,9 @@ pub fn spawn(commands: &mut Commands, asset_server: &AssetServer, dwellings: i32
                     ..default()
                 })
                 .insert(HouseText);
-        });
+        })
+        .insert(Workplace::new(Job::Maintenance))
+        .id()
 }
 
 fn update_house_text(house: &House, upkeep: &upkeep::UpkeepCost, text: &mut Text) {
@@ -0,0 +1,210 @@
+use std::cmp::Ordering;
+
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+use crate::{
+    hunger::{Food, FoodGroup},
+    reproduction::ReproductiveZone,
+};
+
+use super::{
+    building::BuildingStatus, farm::Farm, house::House, voting_center::VotingCenter,
+    workplace::Workplace,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuildingKind {
+    // A farm is listed under every group its food is part of
+    Farm(FoodGroup),
+    // Only operational houses with room to spare
+    House,
+    VotingCenter,
+    ReproductiveZone,
+    // Anywhere that pays people to work, whether or not it is hiring
+    Workplace,
+}
+
+/// On every building the index has listed, whatever it is listed under.
+#[derive(Debug, Component, Default, Clone, Copy)]
+pub struct Indexed;
+
+#[derive(Debug, Clone, Copy)]
+pub struct IndexedBuilding {
+    pub entity: Entity,
+    pub translation: Vec3,
+}
+
+/// Where every building people go looking for is, by what kind of building it is.
+/// Kept up to date as buildings spawn, change status or are removed so goals
+/// don't have to scan every building to find the closest one.
+#[derive(Debug, Default, Resource)]
+pub struct BuildingIndex {
+    kinds: HashMap<BuildingKind, Vec<IndexedBuilding>>,
+}
+
+impl BuildingIndex {
+    pub fn insert(&mut self, kind: BuildingKind, entity: Entity, translation: Vec3) {
+        let buildings = self.kinds.entry(kind).or_default();
+        if buildings.iter().any(|building| building.entity == entity) {
+            return;
+        }
+
+        buildings.push(IndexedBuilding {
+            entity,
+            translation,
+        });
+    }
+
+    pub fn remove_from(&mut self, kind: BuildingKind, entity: Entity) {
+        if let Some(buildings) = self.kinds.get_mut(&kind) {
+            buildings.retain(|building| building.entity != entity);
+        }
+    }
+
+    pub fn remove(&mut self, entity: Entity) {
+        for buildings in self.kinds.values_mut() {
+            buildings.retain(|building| building.entity != entity);
+        }
+    }
+
+    /// Up to `k` buildings of any of the given kinds that pass `filter`, closest first.
+    pub fn k_nearest(
+        &self,
+        kinds: &[BuildingKind],
+        translation: Vec3,
+        k: usize,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Vec<IndexedBuilding> {
+        let position = translation.truncate();
+        let candidates = kinds
+            .iter()
+            .filter_map(|kind| self.kinds.get(kind))
+            .flatten()
+            .filter(|building| filter(building.entity))
+            .map(|building| {
+                (
+                    *building,
+                    building.translation.truncate().distance_squared(position),
+                )
+            });
+        // Ties go to the oldest entity so it doesn't come down to hash map order
+        let closer = |(a, a_distance): &(IndexedBuilding, f32),
+                      (b, b_distance): &(IndexedBuilding, f32)|
+         -> Ordering {
+            a_distance
+                .total_cmp(b_distance)
+                .then(a.entity.cmp(&b.entity))
+        };
+
+        if k == 1 {
+            return candidates
+                .min_by(closer)
+                .map(|(building, _)| building)
+                .into_iter()
+                .collect();
+        }
+
+        // A farm can be listed under more than one of the kinds asked for
+        let mut seen = HashSet::new();
+        let mut found: Vec<_> = candidates
+            .filter(|(building, _)| seen.insert(building.entity))
+            .collect();
+        if found.len() > k && k > 0 {
+            found.select_nth_unstable_by(k - 1, closer);
+        }
+        found.truncate(k);
+        found.sort_by(closer);
+
+        found.into_iter().map(|(building, _)| building).collect()
+    }
+
+    /// The closest building of any of the given kinds that passes `filter`.
+    pub fn nearest(
+        &self,
+        kinds: &[BuildingKind],
+        translation: Vec3,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Option<IndexedBuilding> {
+        self.k_nearest(kinds, translation, 1, filter).pop()
+    }
+}
+
+type HouseChanged = Or<(Changed<House>, Changed<BuildingStatus>)>;
+
+// Buildings that have turned up, or changed in a way that moves them in or out of the index
+#[derive(SystemParam)]
+pub struct BuildingChanges<'w, 's> {
+    farms: Query<'w, 's, (Entity, &'static Transform, &'static Food), Added<Farm>>,
+    houses: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Transform,
+            &'static House,
+            &'static BuildingStatus,
+        ),
+        HouseChanged,
+    >,
+    voting_centers: Query<'w, 's, (Entity, &'static Transform), Added<VotingCenter>>,
+    reproductive_zones: Query<'w, 's, (Entity, &'static Transform), Added<ReproductiveZone>>,
+    workplaces: Query<'w, 's, (Entity, &'static Transform), Added<Workplace>>,
+}
+
+pub fn update_building_index_system(
+    mut commands: Commands,
+    mut index: ResMut<BuildingIndex>,
+    changes: BuildingChanges,
+    mut removed: RemovedComponents<Indexed>,
+) {
+    for entity in removed.iter() {
+        index.remove(entity);
+    }
+
+    let BuildingChanges {
+        farms,
+        houses,
+        voting_centers,
+        reproductive_zones,
+        workplaces,
+    } = changes;
+
+    for (entity, transform, food) in &farms {
+        for group in &food.groups {
+            index.insert(BuildingKind::Farm(*group), entity, transform.translation);
+        }
+        commands.entity(entity).insert(Indexed);
+    }
+
+    for (entity, transform, house, status) in &houses {
+        commands.entity(entity).insert(Indexed);
+        if *status == BuildingStatus::Operational && !house.is_full() {
+            index.insert(BuildingKind::House, entity, transform.translation);
+        } else {
+            index.remove_from(BuildingKind::House, entity);
+        }
+    }
+
+    for (entity, transform) in &voting_centers {
+        index.insert(BuildingKind::VotingCenter, entity, transform.translation);
+        commands.entity(entity).insert(Indexed);
+    }
+
+    for (entity, transform) in &reproductive_zones {
+        index.insert(
+            BuildingKind::ReproductiveZone,
+            entity,
+            transform.translation,
+        );
+        commands.entity(entity).insert(Indexed);
+    }
+
+    for (entity, transform) in &workplaces {
+        index.insert(BuildingKind::Workplace, entity, transform.translation);
+        commands.entity(entity).insert(Indexed);
+    }
+}
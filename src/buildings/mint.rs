@@ -2,10 +2,14 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 use bevy_enum_filter::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     assets,
-    buildings::building,
+    buildings::{
+        building,
+        workplace::{Job, Workplace},
+    },
     money::{Money, Treasury},
     sim_time::SimTime,
 };
@@ -13,7 +17,7 @@ use crate::{
 pub const MINT_MPS_MIN: Money = 500.0;
 pub const MINT_MPS_MAX: Money = 1000.0;
 
-#[derive(Component, Clone, Default)]
+#[derive(Component, Clone, Default, Serialize, Deserialize)]
 pub struct Mint {
     pub money_per_cycle: Money,
     pub timer: Timer,
@@ -43,7 +47,7 @@ pub fn spawn(
     money_per_cycle: Money,
     production_time: Duration,
     location: Vec2,
-) {
+) -> Entity {
     commands
         .spawn(MintBundle {
             farm: Mint {
@@ -77,20 +81,25 @@ pub fn spawn(
                 },
                 ..default()
             });
-        });
+        })
+        .insert(Workplace::new(Job::Minting))
+        .id()
 }
 
 pub fn mint_produce_system(
-    time: Res<Time>,
     sim_time: Res<SimTime>,
     mut treasury: ResMut<Treasury>,
-    mut query: Query<&mut Mint, Without<Enum!(building::BuildingStatus::Dilapidated)>>,
+    mut query: Query<
+        (&mut Mint, Option<&Workplace>),
+        Without<Enum!(building::BuildingStatus::Dilapidated)>,
+    >,
 ) {
-    for mut mint in query.iter_mut() {
-        let finished_count = mint
-            .timer
-            .tick(sim_time.delta(&time))
-            .times_finished_this_tick();
+    for (mut mint, workplace) in query.iter_mut() {
+        // Short staffed mints print slower
+        let delta = sim_time
+            .delta()
+            .mul_f32(workplace.map_or(1.0, |workplace| workplace.output_scale()));
+        let finished_count = mint.timer.tick(delta).times_finished_this_tick();
 
         for _ in 0..finished_count {
             // TODO add plus text
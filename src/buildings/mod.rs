@@ -1,6 +1,10 @@
 pub mod building;
+pub mod construction;
 pub mod farm;
+pub mod granary;
 pub mod house;
+pub mod index;
 pub mod mint;
 pub mod money_hole;
 pub mod voting_center;
+pub mod workplace;
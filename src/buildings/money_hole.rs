@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     assets,
@@ -11,7 +12,7 @@ use super::building;
 pub const MONEY_HOLE_CAPACITY_MIN: Money = 3000.0;
 pub const MONEY_HOLE_CAPACITY_MAX: Money = 10000.0;
 
-#[derive(Component, Clone, Default)]
+#[derive(Component, Clone, Default, Serialize, Deserialize)]
 pub struct MoneyHole {
     pub capacity: Money,
 }
@@ -40,7 +41,7 @@ pub fn spawn(
     asset_server: &AssetServer,
     storage_capacity: Money,
     location: Vec2,
-) {
+) -> Entity {
     let upkeep_cost = storage_capacity / 500.0;
 
     commands
@@ -76,7 +77,8 @@ pub fn spawn(
                 },
                 ..default()
             });
-        });
+        })
+        .id()
 }
 
 pub fn update_treasury_capacity_system(query: Query<&MoneyHole>, mut treasury: ResMut<Treasury>) {
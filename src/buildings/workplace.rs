@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_enum_filter::prelude::*;
+
+use crate::{
+    goals::{self, work::WorkState, Goals},
+    money::Money,
+};
+
+pub const SHIFT_LENGTH: Duration = Duration::from_secs(10);
+// How much of its full output a building manages with nobody working there
+pub const MIN_UNSTAFFED_OUTPUT: f32 = 0.25;
+// How much more upkeep a house costs with nobody maintaining it
+pub const UNMAINTAINED_UPKEEP_SCALE: f64 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Job {
+    Farming,
+    Minting,
+    Maintenance,
+}
+
+impl Job {
+    pub fn positions(&self) -> usize {
+        match self {
+            Job::Farming => 3,
+            Job::Minting => 4,
+            Job::Maintenance => 1,
+        }
+    }
+
+    // Paid at the end of each shift
+    pub fn wage(&self) -> Money {
+        match self {
+            Job::Farming => 50.0,
+            Job::Minting => 70.0,
+            Job::Maintenance => 40.0,
+        }
+    }
+}
+
+/// Paid positions at a building, it only runs at its best with all of them filled.
+#[derive(Debug, Clone, Component)]
+pub struct Workplace {
+    pub job: Job,
+    // Working here or on their way, recounted every tick
+    pub taken: usize,
+    // Working here right now, recounted every tick
+    pub staffed: usize,
+}
+
+impl Workplace {
+    pub fn new(job: Job) -> Self {
+        Self {
+            job,
+            taken: 0,
+            staffed: 0,
+        }
+    }
+
+    pub fn has_opening(&self) -> bool {
+        self.taken < self.job.positions()
+    }
+
+    pub fn staffed_fraction(&self) -> f32 {
+        (self.staffed as f32 / self.job.positions() as f32).min(1.0)
+    }
+
+    /// How much of its full output the building manages with the staff it has.
+    pub fn output_scale(&self) -> f32 {
+        MIN_UNSTAFFED_OUTPUT + (1.0 - MIN_UNSTAFFED_OUTPUT) * self.staffed_fraction()
+    }
+
+    /// How much its upkeep goes up by for want of maintenance.
+    pub fn upkeep_scale(&self) -> f64 {
+        match self.job {
+            Job::Maintenance => {
+                UNMAINTAINED_UPKEEP_SCALE
+                    - (UNMAINTAINED_UPKEEP_SCALE - 1.0) * self.staffed_fraction() as f64
+            }
+            Job::Farming | Job::Minting => 1.0,
+        }
+    }
+}
+
+pub fn count_workers_system(
+    mut workplaces: Query<&mut Workplace>,
+    workers: Query<&Goals, With<Enum!(goals::Goals::Work)>>,
+) {
+    for mut workplace in &mut workplaces {
+        workplace.taken = 0;
+        workplace.staffed = 0;
+    }
+
+    for goal in &workers {
+        let (target, working) = match goal {
+            Goals::Work(WorkState::MovingToWork(target)) => (*target, false),
+            Goals::Work(WorkState::Working { workplace, .. }) => (*workplace, true),
+            _ => continue,
+        };
+
+        if let Ok(mut workplace) = workplaces.get_mut(target) {
+            workplace.taken += 1;
+            if working {
+                workplace.staffed += 1;
+            }
+        }
+    }
+}
@@ -1,14 +1,23 @@
-use bevy::{prelude::*, sprite::collide_aabb::collide};
+use bevy::{prelude::*, sprite::collide_aabb::collide, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    save::{EntityRemap, RemapEntities},
+    sim_time::SimTime,
+};
+
+// Colliders are tiny next to this so most only ever land in one cell
+const CELL_SIZE: f32 = 32.0;
 
 #[derive(Debug, Component, Default, Clone, Copy)]
 pub struct Collider;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 pub struct CollisionEvent {
     pub other: Entity,
 }
 
-#[derive(Debug, Component, Default, Clone)]
+#[derive(Debug, Component, Default, Clone, Serialize, Deserialize)]
 pub struct CollisionHolder {
     pub events: Vec<CollisionEvent>,
 }
@@ -25,36 +34,181 @@ impl CollisionHolder {
     }
 }
 
-#[derive(Debug, Resource)]
+impl RemapEntities for CollisionHolder {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        for event in &mut self.events {
+            event.other = remap.get(event.other);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
 pub struct CollisionTimer(pub Timer);
 
-// TODO should be a quad tree thing I forget the name the one with the squares
+#[derive(Debug, Clone, Copy)]
+pub struct SpatialEntry {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub size: Vec2,
+}
+
+/// Every collider bucketed into a grid of `CELL_SIZE` squares, rebuilt whenever detection runs
+/// so other systems' lookups can be up to one `CollisionTimer` period behind.
+/// Lookups only ever visit the cells an area covers and results come back sorted
+/// so they don't depend on hash map order.
+#[derive(Debug, Default, Resource)]
+pub struct SpatialHash {
+    cells: HashMap<IVec2, Vec<SpatialEntry>>,
+}
+
+impl SpatialHash {
+    fn cell(position: Vec2) -> IVec2 {
+        (position / CELL_SIZE).floor().as_ivec2()
+    }
+
+    fn cells_between(min: Vec2, max: Vec2) -> impl Iterator<Item = IVec2> {
+        let min = Self::cell(min);
+        let max = Self::cell(max);
+
+        (min.x..=max.x).flat_map(move |x| (min.y..=max.y).map(move |y| IVec2::new(x, y)))
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec2, size: Vec2) {
+        let entry = SpatialEntry {
+            entity,
+            position,
+            size,
+        };
+
+        for cell in Self::cells_between(position - size / 2.0, position + size / 2.0) {
+            self.cells.entry(cell).or_default().push(entry);
+        }
+    }
+
+    // Everything in the cells covering the area, each entry once in entity order
+    fn candidates(&self, min: Vec2, max: Vec2) -> Vec<SpatialEntry> {
+        let mut candidates: Vec<_> = Self::cells_between(min, max)
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .collect();
+        candidates.sort_by_key(|entry| entry.entity);
+        candidates.dedup_by_key(|entry| entry.entity);
+
+        candidates
+    }
+
+    /// Every entity whose box overlaps the given box.
+    pub fn overlapping(&self, position: Vec2, size: Vec2) -> Vec<Entity> {
+        self.candidates(position - size / 2.0, position + size / 2.0)
+            .into_iter()
+            .filter(|entry| {
+                collide(
+                    position.extend(0.0),
+                    size,
+                    entry.position.extend(0.0),
+                    entry.size,
+                )
+                .is_some()
+            })
+            .map(|entry| entry.entity)
+            .collect()
+    }
+
+    /// Every entity whose centre is within `radius` of `center`, closest first.
+    pub fn within_radius(&self, center: Vec2, radius: f32) -> Vec<Entity> {
+        let mut found: Vec<_> = self
+            .candidates(center - Vec2::splat(radius), center + Vec2::splat(radius))
+            .into_iter()
+            .map(|entry| (entry.entity, entry.position.distance_squared(center)))
+            .filter(|(_, distance)| *distance <= radius * radius)
+            .collect();
+        found.sort_by(|(a, a_distance), (b, b_distance)| {
+            a_distance.total_cmp(b_distance).then(a.cmp(b))
+        });
+
+        found.into_iter().map(|(entity, _)| entity).collect()
+    }
+
+    /// The closest entity within `radius` that passes `filter`.
+    pub fn nearest(
+        &self,
+        center: Vec2,
+        radius: f32,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Option<Entity> {
+        self.within_radius(center, radius)
+            .into_iter()
+            .find(|entity| filter(*entity))
+    }
+}
+
+// Holders keep their last events until the timer next runs detection
 pub fn collision_detection_system(
-    mut collision_q: Query<(Entity, &Transform, &mut CollisionHolder), With<Collider>>,
+    sim_time: Res<SimTime>,
+    mut timer: ResMut<CollisionTimer>,
+    mut spatial_hash: ResMut<SpatialHash>,
     colliders_q: Query<(Entity, &Transform), With<Collider>>,
+    mut collision_q: Query<(Entity, &Transform, &mut CollisionHolder), With<Collider>>,
 ) {
-    for (entity, transform, mut col) in &mut collision_q {
-        let size = transform.scale.truncate();
+    if !timer.0.tick(sim_time.delta()).just_finished() {
+        return;
+    }
 
-        col.events.clear();
-        for (other_entity, other_trans) in &colliders_q {
-            if entity == other_entity {
-                continue;
-            }
+    spatial_hash.clear();
+    for (entity, transform) in &colliders_q {
+        spatial_hash.insert(
+            entity,
+            transform.translation.truncate(),
+            transform.scale.truncate(),
+        );
+    }
 
-            let collision: Option<bevy::sprite::collide_aabb::Collision> = collide(
-                transform.translation,
-                size,
-                other_trans.translation,
-                other_trans.scale.truncate(),
-            );
-            if collision.is_none() {
+    for (entity, transform, mut col) in &mut collision_q {
+        col.events.clear();
+        for other in
+            spatial_hash.overlapping(transform.translation.truncate(), transform.scale.truncate())
+        {
+            if other == entity {
                 continue;
             }
 
-            col.events.push(CollisionEvent {
-                other: other_entity,
-            });
+            col.events.push(CollisionEvent { other });
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::SpatialHash;
+
+    #[test]
+    fn radius_lookups_come_back_closest_first() {
+        let mut spatial_hash = SpatialHash::default();
+        let size = Vec2::splat(4.0);
+        spatial_hash.insert(Entity::from_raw(0), Vec2::new(30.0, 0.0), size);
+        spatial_hash.insert(Entity::from_raw(1), Vec2::new(-10.0, 0.0), size);
+        spatial_hash.insert(Entity::from_raw(2), Vec2::new(100.0, 100.0), size);
+        spatial_hash.insert(Entity::from_raw(3), Vec2::new(0.0, 5.0), size);
+
+        assert_eq!(
+            spatial_hash.within_radius(Vec2::ZERO, 40.0),
+            vec![
+                Entity::from_raw(3),
+                Entity::from_raw(1),
+                Entity::from_raw(0)
+            ]
+        );
+        assert_eq!(
+            spatial_hash.nearest(Vec2::ZERO, 40.0, |entity| entity.index() < 2),
+            Some(Entity::from_raw(1))
+        );
+        assert_eq!(spatial_hash.nearest(Vec2::ZERO, 5.0, |_| false), None);
+    }
+}
@@ -3,14 +3,16 @@ use std::{collections::HashSet, ops::Div, time::Duration};
 use bevy::prelude::*;
 use enum_iterator::Sequence;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-use crate::{age, energy, rng, shelter, sim_time::SimTime};
+use crate::{age, energy, hunger, rng, shelter, sim_time::SimTime};
 
-#[derive(Debug, PartialEq, Hash, Copy, Clone, Sequence)]
+#[derive(Debug, PartialEq, Hash, Copy, Clone, Sequence, Serialize, Deserialize)]
 pub enum DeathReason {
     Starvation,
     OldAge,
     Homeliness,
+    Malnutrition,
 }
 
 impl DeathReason {
@@ -19,6 +21,7 @@ impl DeathReason {
             DeathReason::Starvation => "I am starving".to_string(),
             DeathReason::OldAge => "I am old".to_string(),
             DeathReason::Homeliness => "I am homeless".to_owned(),
+            DeathReason::Malnutrition => "I am malnourished".to_owned(),
         }
     }
 
@@ -27,13 +30,14 @@ impl DeathReason {
             DeathReason::Starvation => "Starved".to_string(),
             DeathReason::OldAge => "Olded to death".to_string(),
             DeathReason::Homeliness => "Exposure".to_owned(),
+            DeathReason::Malnutrition => "Malnutrition".to_owned(),
         }
     }
 }
 
 impl Eq for DeathReason {}
 
-#[derive(Debug, Component, Default)]
+#[derive(Debug, Clone, Component, Default, Serialize, Deserialize)]
 pub struct Mortal {
     pub dead: Option<DeathReason>,
     pub at_risk: HashSet<DeathReason>,
@@ -41,7 +45,6 @@ pub struct Mortal {
 }
 
 pub fn death_from_exhaustion_system(
-    time: Res<Time>,
     sim_time: Res<SimTime>,
     mut query: Query<(&mut Mortal, &energy::Energy)>,
 ) {
@@ -56,7 +59,7 @@ pub fn death_from_exhaustion_system(
             }
 
             let mut depleted_for = mortal.energy_depleted_for.unwrap();
-            depleted_for += sim_time.delta(&time);
+            depleted_for += sim_time.delta();
             if depleted_for > std::time::Duration::from_secs(30) {
                 mortal.dead = Some(DeathReason::Starvation);
             }
@@ -71,21 +74,16 @@ pub fn death_from_exhaustion_system(
 
 pub const OLD_AGE_DEATH_THRESHOLD: std::time::Duration = std::time::Duration::from_secs(250);
 
-#[derive(Resource)]
+#[derive(Clone, Resource, Serialize, Deserialize)]
 pub struct CheckOldAgeTimer(pub Timer);
 
 pub fn die_of_old_age_system(
-    time: Res<Time>,
     sim_time: Res<SimTime>,
     mut rng: ResMut<rng::Rng>,
     mut timer: ResMut<CheckOldAgeTimer>,
     mut query: Query<(&mut Mortal, &age::Age)>,
 ) {
-    for _ in 0..timer
-        .0
-        .tick(sim_time.delta(&time))
-        .times_finished_this_tick()
-    {
+    for _ in 0..timer.0.tick(sim_time.delta()).times_finished_this_tick() {
         for (mut mortal, age) in &mut query {
             let death_threshold = OLD_AGE_DEATH_THRESHOLD;
 
@@ -126,6 +124,25 @@ pub fn die_of_homelessness_system(mut query: Query<(&mut Mortal, &shelter::Requi
     }
 }
 
+pub fn die_of_malnutrition_system(mut query: Query<(&mut Mortal, &hunger::Nutrition)>) {
+    for (mut mortal, nutrition) in &mut query {
+        const MALNUTRITION_DEATH_THRESHOLD: Duration = Duration::from_secs(90);
+
+        if nutrition.malnourished_for < MALNUTRITION_DEATH_THRESHOLD / 2 {
+            mortal.at_risk.remove(&DeathReason::Malnutrition);
+            continue;
+        }
+
+        if !mortal.at_risk.contains(&DeathReason::Malnutrition) {
+            mortal.at_risk.insert(DeathReason::Malnutrition);
+        }
+
+        if nutrition.malnourished_for > MALNUTRITION_DEATH_THRESHOLD {
+            mortal.dead = Some(DeathReason::Malnutrition);
+        }
+    }
+}
+
 pub fn remove_dead_system(mut commands: Commands, query: Query<(Entity, &Mortal)>) {
     for (entity, mortal) in &query {
         if let Some(reason) = &mortal.dead {
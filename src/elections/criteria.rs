@@ -0,0 +1,333 @@
+use std::fmt;
+
+use serde::Serialize;
+use voties::tally::{
+    pairwise::PairwiseMatrix,
+    voting_methods::{fill, vote_bundle, MandatoryPreferentialBallot, OptionRating},
+    ElectionType,
+};
+
+use super::election::{exclude_options, Election, ElectionOption, ElectionTypeResult};
+
+#[derive(Debug, Clone, Serialize)]
+pub enum Paradox {
+    // Beat every other option head to head but didn't win
+    CondorcetWinnerMissed {
+        election_type: ElectionType,
+        condorcet_winner: ElectionOption,
+    },
+    // Lost to every other option head to head but still won
+    CondorcetLoserElected {
+        election_type: ElectionType,
+        condorcet_loser: ElectionOption,
+    },
+    // First choice of more than half the voters but didn't win
+    MajorityWinnerMissed {
+        election_type: ElectionType,
+        majority_winner: ElectionOption,
+    },
+    // Taking a losing option off the ballot would have changed who won
+    Spoiler {
+        election_type: ElectionType,
+        spoiler: ElectionOption,
+        winners_without: Vec<ElectionOption>,
+    },
+    // Every option in it beats the next, and the last beats the first
+    PairwiseCycle {
+        options: Vec<ElectionOption>,
+    },
+}
+
+fn options_to_string(options: &[ElectionOption]) -> String {
+    options
+        .iter()
+        .map(|option| option.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl fmt::Display for Paradox {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Paradox::CondorcetWinnerMissed {
+                election_type,
+                condorcet_winner,
+            } => write!(
+                f,
+                "{} missed the Condorcet winner {}",
+                election_type.to_string(),
+                condorcet_winner.to_string()
+            ),
+            Paradox::CondorcetLoserElected {
+                election_type,
+                condorcet_loser,
+            } => write!(
+                f,
+                "{} elected the Condorcet loser {}",
+                election_type.to_string(),
+                condorcet_loser.to_string()
+            ),
+            Paradox::MajorityWinnerMissed {
+                election_type,
+                majority_winner,
+            } => write!(
+                f,
+                "{} missed the majority's choice {}",
+                election_type.to_string(),
+                majority_winner.to_string()
+            ),
+            Paradox::Spoiler {
+                election_type,
+                spoiler,
+                winners_without,
+            } => write!(
+                f,
+                "{} was spoiled by {}, without them {} wins",
+                election_type.to_string(),
+                spoiler.to_string(),
+                options_to_string(winners_without)
+            ),
+            Paradox::PairwiseCycle { options } => {
+                write!(f, "Pairwise cycle between {}", options_to_string(options))
+            }
+        }
+    }
+}
+
+/// How one method's winners stand up against the ballots it was counted on.
+#[derive(Debug, Clone, Serialize)]
+pub struct MethodCriteria {
+    pub election_type: ElectionType,
+    pub condorcet_winner: Option<ElectionOption>,
+    pub condorcet_loser: Option<ElectionOption>,
+    pub majority_winner: Option<ElectionOption>,
+    // Losing options whose removal changes the winners
+    pub spoilers: Vec<(ElectionOption, Vec<ElectionOption>)>,
+    pub picked_condorcet_winner: Option<bool>,
+    pub picked_condorcet_loser: Option<bool>,
+    pub picked_majority_winner: Option<bool>,
+}
+
+impl MethodCriteria {
+    fn new(election: &Election, result: &ElectionTypeResult) -> Self {
+        let election_type = result.get_type();
+        let votes = election.cast_option_ratings(election_type);
+        let option_ratings = votes.iter().collect::<Vec<_>>();
+        let matrix = pairwise_matrix(election.options.len(), &option_ratings);
+        let winners = result.get_winners();
+
+        let condorcet_winner = condorcet_winner(&matrix).map(|i| election.options[i].clone());
+        let condorcet_loser = condorcet_loser(&matrix).map(|i| election.options[i].clone());
+        let majority_winner = majority_winner(election.options.len(), &option_ratings)
+            .map(|i| election.options[i].clone());
+
+        let picked_condorcet_winner = condorcet_winner
+            .as_ref()
+            .map(|option| winners.contains(&option));
+        let picked_condorcet_loser = condorcet_loser
+            .as_ref()
+            .map(|option| winners.contains(&option));
+        let picked_majority_winner = majority_winner
+            .as_ref()
+            .map(|option| winners.contains(&option));
+
+        let mut spoilers = vec![];
+        // Nothing is left to pick from with only the winners and one other on the ballot
+        if election.options.len() > winners.len() + 1 {
+            for option in &election.options {
+                if winners.contains(&option) {
+                    continue;
+                }
+
+                let (options, votes) = exclude_options(
+                    &election.options,
+                    &option_ratings,
+                    std::slice::from_ref(option),
+                );
+                let option_ratings = votes.iter().collect::<Vec<_>>();
+                let without = election_type.result(
+                    &options,
+                    &option_ratings,
+                    election.seats.min(options.len()),
+                );
+                let winners_without = without.get_winners();
+                // Multi winner methods may fill their seats in a different order
+                if winners_without.len() != winners.len()
+                    || !winners_without
+                        .iter()
+                        .all(|winner| winners.contains(winner))
+                {
+                    spoilers.push((
+                        option.clone(),
+                        winners_without.into_iter().cloned().collect(),
+                    ));
+                }
+            }
+        }
+
+        Self {
+            election_type,
+            condorcet_winner,
+            condorcet_loser,
+            majority_winner,
+            spoilers,
+            picked_condorcet_winner,
+            picked_condorcet_loser,
+            picked_majority_winner,
+        }
+    }
+
+    fn paradoxes(&self) -> Vec<Paradox> {
+        let mut paradoxes = vec![];
+        if let (Some(false), Some(condorcet_winner)) =
+            (self.picked_condorcet_winner, &self.condorcet_winner)
+        {
+            paradoxes.push(Paradox::CondorcetWinnerMissed {
+                election_type: self.election_type,
+                condorcet_winner: condorcet_winner.clone(),
+            });
+        }
+        if let (Some(true), Some(condorcet_loser)) =
+            (self.picked_condorcet_loser, &self.condorcet_loser)
+        {
+            paradoxes.push(Paradox::CondorcetLoserElected {
+                election_type: self.election_type,
+                condorcet_loser: condorcet_loser.clone(),
+            });
+        }
+        if let (Some(false), Some(majority_winner)) =
+            (self.picked_majority_winner, &self.majority_winner)
+        {
+            paradoxes.push(Paradox::MajorityWinnerMissed {
+                election_type: self.election_type,
+                majority_winner: majority_winner.clone(),
+            });
+        }
+        for (spoiler, winners_without) in &self.spoilers {
+            paradoxes.push(Paradox::Spoiler {
+                election_type: self.election_type,
+                spoiler: spoiler.clone(),
+                winners_without: winners_without.clone(),
+            });
+        }
+
+        paradoxes
+    }
+}
+
+/// Which voting criteria each method met in one election, and anything odd that came of it.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Criteria {
+    // Over the ballots actually cast
+    pub cycle: Option<Vec<ElectionOption>>,
+    // In the same order as the held election's results
+    pub methods: Vec<MethodCriteria>,
+    pub paradoxes: Vec<Paradox>,
+}
+
+impl Criteria {
+    pub fn new(election: &Election, results: &[ElectionTypeResult]) -> Self {
+        let methods = results
+            .iter()
+            .map(|result| MethodCriteria::new(election, result))
+            .collect::<Vec<_>>();
+
+        let matrix = pairwise_matrix(election.options.len(), &election.get_option_ratings());
+        let cycle = pairwise_cycle(&matrix).map(|cycle| {
+            cycle
+                .into_iter()
+                .map(|i| election.options[i].clone())
+                .collect::<Vec<_>>()
+        });
+
+        let mut paradoxes = vec![];
+        if let Some(options) = &cycle {
+            paradoxes.push(Paradox::PairwiseCycle {
+                options: options.clone(),
+            });
+        }
+        for method in &methods {
+            paradoxes.extend(method.paradoxes());
+        }
+
+        Self {
+            cycle,
+            methods,
+            paradoxes,
+        }
+    }
+}
+
+fn pairwise_matrix(
+    option_count: usize,
+    option_ratings: &Vec<&Vec<OptionRating>>,
+) -> PairwiseMatrix {
+    let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+    PairwiseMatrix::new(option_count, &vote_bundle(&votes))
+}
+
+fn condorcet_winner(matrix: &PairwiseMatrix) -> Option<usize> {
+    (0..matrix.option_count())
+        .find(|a| (0..matrix.option_count()).all(|b| *a == b || matrix.beats(*a, b)))
+}
+
+fn condorcet_loser(matrix: &PairwiseMatrix) -> Option<usize> {
+    (0..matrix.option_count())
+        .find(|a| (0..matrix.option_count()).all(|b| *a == b || matrix.beats(b, *a)))
+}
+
+fn majority_winner(option_count: usize, option_ratings: &[&Vec<OptionRating>]) -> Option<usize> {
+    let mut first_preferences = vec![0; option_count];
+    for ballot in option_ratings {
+        if let Some(first) = ballot.first() {
+            first_preferences[first.option_index] += 1;
+        }
+    }
+
+    (0..option_count).find(|i| first_preferences[*i] * 2 > option_ratings.len())
+}
+
+// The first cycle found walking the options in order, each option beats the one after it
+fn pairwise_cycle(matrix: &PairwiseMatrix) -> Option<Vec<usize>> {
+    fn visit(
+        matrix: &PairwiseMatrix,
+        path: &mut Vec<usize>,
+        done: &mut [bool],
+    ) -> Option<Vec<usize>> {
+        let current = *path.last().unwrap();
+        for next in 0..matrix.option_count() {
+            if !matrix.beats(current, next) {
+                continue;
+            }
+
+            if let Some(start) = path.iter().position(|option| *option == next) {
+                return Some(path[start..].to_vec());
+            }
+            if done[next] {
+                continue;
+            }
+
+            path.push(next);
+            if let Some(cycle) = visit(matrix, path, done) {
+                return Some(cycle);
+            }
+            path.pop();
+        }
+        // Every cycle through here would have been found already
+        done[current] = true;
+
+        None
+    }
+
+    let mut done = vec![false; matrix.option_count()];
+    for start in 0..matrix.option_count() {
+        if done[start] {
+            continue;
+        }
+        if let Some(cycle) = visit(matrix, &mut vec![start], &mut done) {
+            return Some(cycle);
+        }
+    }
+
+    None
+}
@@ -1,60 +1,94 @@
-use std::{collections::HashSet, time::Duration};
+use std::{collections::VecDeque, time::Duration};
 
-use bevy::{prelude::*, utils::HashMap};
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
 use rand::seq::{IteratorRandom, SliceRandom};
+use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
-use strum_macros::EnumIter;
 
 use crate::{
     age::Age,
-    buildings::building::{Building, BuildingPlots},
+    buildings::{
+        building::{Building, BuildingPlots},
+        construction::{self, ConstructionOutcome, ConstructionQueue},
+        workplace::Job,
+    },
     death::{DeathReason, Mortal},
     energy::Energy,
-    hunger::{FoodCollection, FoodPreferences, FoodTemplate, Stomach},
+    goals::work,
+    hunger::{FoodCollection, FoodPreferences, FoodTemplate, Nutrition, Stomach},
+    money::{Treasury, Wallet},
     name,
     reproduction::Reproductive,
     rng,
+    save::{EntityRemap, RemapEntities},
+    scenario::Scenario,
     shelter::RequiresHouse,
     sim_time::SimTime,
+    tax::{Tax, TaxPolicy},
     world_stats::WorldStats,
 };
+use voties::tally::{self, voting_methods::OptionRating, want_level, ElectionType};
 
 use super::{
-    anti_plurality::{self, AntiPluralityResult},
-    approval::{Approval, ApprovalResult},
-    first_pass_the_post::{FirstPastThePost, FirstPastThePostResult},
-    good_ok_bad::{GoodOkBadElection, GoodOkBadResult},
-    preferential::Preferential,
-    preferential::PreferentialResult,
-    star::{Star, StarResult},
-    usual_judgment::{self, UsualJudgmentResult},
+    criteria::Criteria,
+    satisfaction::Satisfaction,
+    strategy::{self, Poll},
+    turnout::{self, Demographic, Turnout},
     voter::Voter,
-    voting_methods::OptionRating,
 };
 
+const MIN_SEATS: usize = 2;
+const MAX_SEATS: usize = 3;
+
 #[derive(Debug, Clone)]
 pub struct HeldElection {
     pub name: String,
     pub election: Election,
     pub results: Vec<ElectionTypeResult>,
+    // What came of each winner, in the order they were tried
+    pub construction: Vec<ConstructionOutcome>,
+    pub turnout: Turnout,
+    // What every method would have picked had everyone voted sincerely, in the same order as results
+    pub sincere_results: Vec<ElectionTypeResult>,
+    pub satisfaction: Satisfaction,
+    pub criteria: Criteria,
 }
 
 impl HeldElection {
-    pub fn new(title: &str, election: Election) -> Self {
+    pub fn new(title: &str, election: Election, construction: Vec<ConstructionOutcome>) -> Self {
         let mut results = vec![election.result()];
+        let mut sincere_results = vec![election.sincere_result_for(election.election_type)];
         for election_type in ElectionType::iter() {
             if election.election_type == election_type {
                 continue;
             }
             results.push(election.result_for(election_type));
+            sincere_results.push(election.sincere_result_for(election_type));
         }
 
         Self {
             name: title.to_string(),
+            turnout: Turnout::new(&election),
+            satisfaction: Satisfaction::new(&election, &results, &sincere_results),
+            criteria: Criteria::new(&election, &results),
+            sincere_results,
             election,
             results,
+            construction,
         }
     }
+
+    pub fn construction_to_string(&self) -> String {
+        self.construction
+            .iter()
+            .map(|outcome| outcome.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 #[derive(Debug, Resource, Default)]
@@ -67,94 +101,9 @@ pub struct ElectionClosedEvent {
     pub held_election: HeldElection,
 }
 
-#[derive(Debug, Clone)]
-pub enum ElectionTypeResult {
-    FirstPastThePostResult(FirstPastThePostResult),
-    ApprovalResult(ApprovalResult),
-    PreferentialResult(PreferentialResult),
-    GoodOkBadResult(GoodOkBadResult),
-    StarResult(StarResult),
-    AntiPluralityResult(AntiPluralityResult),
-    UsualJudgment(UsualJudgmentResult),
-}
-
-impl ElectionTypeResult {
-    pub fn get_winner(&self) -> &ElectionOption {
-        match self {
-            ElectionTypeResult::FirstPastThePostResult(result) => &result.winner,
-            ElectionTypeResult::ApprovalResult(result) => &result.winner,
-            ElectionTypeResult::PreferentialResult(result) => &result.winner,
-            ElectionTypeResult::GoodOkBadResult(result) => &result.winner,
-            ElectionTypeResult::StarResult(result) => &result.winner,
-            ElectionTypeResult::AntiPluralityResult(result) => &result.winner,
-            ElectionTypeResult::UsualJudgment(result) => &result.winner,
-        }
-    }
-
-    pub fn get_type(&self) -> ElectionType {
-        match self {
-            ElectionTypeResult::FirstPastThePostResult(_) => ElectionType::FirstPastThePost,
-            ElectionTypeResult::ApprovalResult(_) => ElectionType::Approval,
-            ElectionTypeResult::PreferentialResult(_) => ElectionType::Preferential,
-            ElectionTypeResult::GoodOkBadResult(_) => ElectionType::GoodOkBad,
-            ElectionTypeResult::StarResult(_) => ElectionType::Star,
-            ElectionTypeResult::AntiPluralityResult(_) => ElectionType::AntiPlurality,
-            ElectionTypeResult::UsualJudgment(_) => ElectionType::UsualJudgment,
-        }
-    }
-}
-
-pub trait ElectionImpl {
-    fn result(
-        options: &[ElectionOption],
-        option_ratings: &Vec<&Vec<OptionRating>>,
-    ) -> ElectionTypeResult;
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, EnumIter)]
-pub enum ElectionType {
-    FirstPastThePost,
-    Approval,
-    Preferential,
-    GoodOkBad,
-    Star,
-    AntiPlurality,
-    UsualJudgment,
-}
+pub type ElectionTypeResult = tally::ElectionTypeResult<ElectionOption>;
 
-impl ElectionType {
-    pub fn result(
-        &self,
-        options: &[ElectionOption],
-        option_ratings: &Vec<&Vec<OptionRating>>,
-    ) -> ElectionTypeResult {
-        match self {
-            ElectionType::FirstPastThePost => FirstPastThePost::result(options, option_ratings),
-            ElectionType::Approval => Approval::result(options, option_ratings),
-            ElectionType::Preferential => Preferential::result(options, option_ratings),
-            ElectionType::GoodOkBad => GoodOkBadElection::result(options, option_ratings),
-            ElectionType::Star => Star::result(options, option_ratings),
-            ElectionType::AntiPlurality => anti_plurality::result(options, option_ratings),
-            ElectionType::UsualJudgment => usual_judgment::result(options, option_ratings),
-        }
-    }
-}
-
-impl ToString for ElectionType {
-    fn to_string(&self) -> String {
-        match self {
-            ElectionType::FirstPastThePost => "First Past The Post".to_string(),
-            ElectionType::Approval => "Approval".to_string(),
-            ElectionType::Preferential => "Preferential".to_string(),
-            ElectionType::GoodOkBad => "Good Ok Bad".to_string(),
-            ElectionType::Star => "Star".to_string(),
-            ElectionType::AntiPlurality => "Anti Plurality".to_string(),
-            ElectionType::UsualJudgment => "Usual Judgment".to_string(),
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ElectionOption {
     DoNothing,
     MakeFarm(FoodTemplate),
@@ -162,6 +111,9 @@ pub enum ElectionOption {
     MoneyHole,
     Mint,
     House(i32),
+    Granary,
+    // Changes one tax from the rate it was at when the election opened
+    SetTax { tax: Tax, from: u32, to: u32 },
 }
 
 impl ToString for ElectionOption {
@@ -175,32 +127,203 @@ impl ToString for ElectionOption {
             ElectionOption::MoneyHole => format!("Make a money hole"),
             ElectionOption::Mint => format!("Make a mint"),
             ElectionOption::House(dwellings) => format!("Make a {} bedroom house", dwellings),
+            ElectionOption::Granary => "Make a granary".to_owned(),
+            ElectionOption::SetTax { tax, to: 0, .. } => format!("Abolish {}", tax),
+            ElectionOption::SetTax { tax, from, to } => format!(
+                "{} {} to {}",
+                if to > from { "Raise" } else { "Lower" },
+                tax,
+                tax.rate_to_string(*to)
+            ),
+        }
+    }
+}
+
+impl ElectionOption {
+    pub fn building(&self) -> Option<Building> {
+        match self {
+            ElectionOption::DoNothing => None,
+            ElectionOption::MakeFarm(food_template) => Some(Building::Farm(food_template.clone())),
+            ElectionOption::MakeRz => Some(Building::ReproductiveZone),
+            ElectionOption::MoneyHole => Some(Building::MoneyHole),
+            ElectionOption::Mint => Some(Building::Mint),
+            ElectionOption::House(dwellings) => Some(Building::House(*dwellings)),
+            ElectionOption::Granary => Some(Building::Granary),
+            ElectionOption::SetTax { .. } => None,
+        }
+    }
+
+    /// The work the building would give people, if any.
+    pub fn job(&self) -> Option<Job> {
+        match self {
+            ElectionOption::MakeFarm(_) => Some(Job::Farming),
+            ElectionOption::Mint => Some(Job::Minting),
+            ElectionOption::House(_) => Some(Job::Maintenance),
+            ElectionOption::DoNothing
+            | ElectionOption::MakeRz
+            | ElectionOption::MoneyHole
+            | ElectionOption::Granary
+            | ElectionOption::SetTax { .. } => None,
         }
     }
 }
 
-#[derive(Debug, Clone, Component)]
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct Election {
     pub options: Vec<ElectionOption>,
     pub election_type: ElectionType,
+    // How many options multi winner methods pick
+    pub seats: usize,
     pub votes: HashMap<Entity, Vec<OptionRating>>,
     pub time_open: Duration,
+    // Closes once it has been open this long
+    pub open_for: Duration,
+    // Everyone who could vote, as they were when they made up their mind or when it closed
+    #[serde(default)]
+    pub electorate: HashMap<Entity, Demographic>,
+    // Those who decided not to bother
+    #[serde(default)]
+    pub abstained: HashSet<Entity>,
+    // How strategic voters really felt
+    #[serde(default)]
+    pub sincere_votes: HashMap<Entity, Vec<OptionRating>>,
+    // The latest poll, strategic voters work around it
+    #[serde(default)]
+    pub poll: Option<Poll>,
 }
 
 impl Election {
-    fn get_option_ratings(&self) -> Vec<&Vec<OptionRating>> {
-        let option_ratings = self.votes.values().collect::<Vec<_>>();
-        option_ratings
+    pub fn get_option_ratings(&self) -> Vec<&Vec<OptionRating>> {
+        // Sorted by voter so tie breaks don't depend on hash map order
+        let mut votes = self.votes.iter().collect::<Vec<_>>();
+        votes.sort_by_key(|(voter, _)| **voter);
+
+        votes.into_iter().map(|(_, ratings)| ratings).collect()
+    }
+
+    // Every ballot as the voter really felt, whatever they cast
+    pub fn sincere_option_ratings(&self) -> Vec<&Vec<OptionRating>> {
+        let mut votes = self.votes.iter().collect::<Vec<_>>();
+        votes.sort_by_key(|(voter, _)| **voter);
+
+        votes
+            .into_iter()
+            .map(|(voter, ratings)| self.sincere_votes.get(voter).unwrap_or(ratings))
+            .collect()
+    }
+
+    pub fn sincere_result_for(&self, election_type: ElectionType) -> ElectionTypeResult {
+        let option_ratings = self.sincere_option_ratings();
+        election_type.result(&self.options, &option_ratings, self.seats)
+    }
+
+    // Ballots as they'd have been cast under the given method, strategic voters vote around the
+    // latest poll differently for each method
+    pub fn cast_option_ratings(&self, election_type: ElectionType) -> Vec<Vec<OptionRating>> {
+        // What was actually cast, they may have voted off an earlier poll
+        if election_type == self.election_type {
+            return self.get_option_ratings().into_iter().cloned().collect();
+        }
+
+        let mut votes = self.votes.iter().collect::<Vec<_>>();
+        votes.sort_by_key(|(voter, _)| **voter);
+
+        votes
+            .into_iter()
+            .map(
+                |(voter, ratings)| match (self.sincere_votes.get(voter), &self.poll) {
+                    (Some(sincere), Some(poll)) => {
+                        strategy::strategic_ratings(election_type, sincere, poll)
+                    }
+                    _ => ratings.clone(),
+                },
+            )
+            .collect()
     }
 
     pub fn result_for(&self, election_type: ElectionType) -> ElectionTypeResult {
-        let option_ratings = self.get_option_ratings();
-        election_type.result(&self.options, &option_ratings)
+        let votes = self.cast_option_ratings(election_type);
+        let option_ratings = votes.iter().collect::<Vec<_>>();
+        election_type.result(&self.options, &option_ratings, self.seats)
     }
 
     pub fn result(&self) -> ElectionTypeResult {
         self.result_for(self.election_type)
     }
+
+    // The result as if the excluded options were never on the ballot
+    pub fn result_excluding(&self, excluded: &[ElectionOption]) -> ElectionTypeResult {
+        let (options, votes) = exclude_options(&self.options, &self.get_option_ratings(), excluded);
+        let option_ratings = votes.iter().collect::<Vec<_>>();
+
+        self.election_type
+            .result(&options, &option_ratings, self.seats.min(options.len()))
+    }
+}
+
+/// The options and ballots left once the excluded options are struck off, ballots keep their order.
+pub fn exclude_options(
+    options: &[ElectionOption],
+    option_ratings: &[&Vec<OptionRating>],
+    excluded: &[ElectionOption],
+) -> (Vec<ElectionOption>, Vec<Vec<OptionRating>>) {
+    let kept = (0..options.len())
+        .filter(|i| !excluded.contains(&options[*i]))
+        .collect::<Vec<_>>();
+    let kept_options = kept.iter().map(|i| options[*i].clone()).collect::<Vec<_>>();
+
+    let votes = option_ratings
+        .iter()
+        .map(|ratings| {
+            ratings
+                .iter()
+                .filter_map(|rating| {
+                    kept.iter()
+                        .position(|i| *i == rating.option_index)
+                        .map(|option_index| OptionRating {
+                            option_index,
+                            rating: rating.rating,
+                        })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    (kept_options, votes)
+}
+
+impl RemapEntities for Election {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        // In voter order so voters who are gone are stood in for the same way every load
+        let mut votes = self.votes.drain().collect::<Vec<_>>();
+        votes.sort_by_key(|(voter, _)| *voter);
+
+        self.votes = votes
+            .into_iter()
+            .map(|(voter, ratings)| (remap.get(voter), ratings))
+            .collect();
+
+        let mut electorate = self.electorate.drain().collect::<Vec<_>>();
+        electorate.sort_by_key(|(voter, _)| *voter);
+        self.electorate = electorate
+            .into_iter()
+            .map(|(voter, demographic)| (remap.get(voter), demographic))
+            .collect();
+
+        let mut sincere_votes = self.sincere_votes.drain().collect::<Vec<_>>();
+        sincere_votes.sort_by_key(|(voter, _)| *voter);
+        self.sincere_votes = sincere_votes
+            .into_iter()
+            .map(|(voter, ratings)| (remap.get(voter), ratings))
+            .collect();
+
+        let mut abstained = self.abstained.drain().collect::<Vec<_>>();
+        abstained.sort();
+        self.abstained = abstained
+            .into_iter()
+            .map(|voter| remap.get(voter))
+            .collect();
+    }
 }
 
 #[derive(Debug)]
@@ -211,16 +334,8 @@ pub struct VoterAttributes<'a> {
     pub food_preferences: Option<&'a FoodPreferences>,
     pub reproductive: Option<&'a Reproductive>,
     pub housing: Option<&'a RequiresHouse>,
-}
-
-pub mod want_level {
-    pub const EXTREMELY_NEGATIVE: i32 = -30;
-    pub const NEGATIVE: i32 = -20;
-    pub const SLIGHTLY_NEGATIVE: i32 = -10;
-    pub const NEUTRAL: i32 = 0;
-    pub const SLIGHTLY_POSITIVE: i32 = 10;
-    pub const POSITIVE: i32 = 20;
-    pub const EXTREMELY_POSITIVE: i32 = 30;
+    pub wallet: Option<&'a Wallet>,
+    pub nutrition: Option<&'a Nutrition>,
 }
 
 fn modify_rating(
@@ -236,6 +351,9 @@ fn modify_rating(
         ElectionOption::MoneyHole => attributes.voter.money_care,
         ElectionOption::Mint => attributes.voter.money_care,
         ElectionOption::House(_) => attributes.voter.housing_care,
+        ElectionOption::Granary => attributes.voter.food_care,
+        // Money care is already in how much the tax would cost them
+        ElectionOption::SetTax { .. } => 0,
     };
 
     let mut rating = rating;
@@ -258,6 +376,26 @@ impl Election {
             return;
         }
 
+        let sincere = self.sincere_ratings(rng, &attributes, stats);
+        match &self.poll {
+            Some(poll) if attributes.voter.strategic => {
+                let cast = strategy::strategic_ratings(self.election_type, &sincere, poll);
+                self.votes.insert(voter, cast);
+                self.sincere_votes.insert(voter, sincere);
+            }
+            _ => {
+                self.votes.insert(voter, sincere);
+            }
+        }
+    }
+
+    /// How someone really rates each option, best first.
+    pub fn sincere_ratings(
+        &self,
+        rng: &mut impl rand::Rng,
+        attributes: &VoterAttributes,
+        stats: &WorldStats,
+    ) -> Vec<OptionRating> {
         let mut option_ratings = vec![];
 
         for (index, option) in self.options.iter().enumerate() {
@@ -278,7 +416,40 @@ impl Election {
                                 rating = want_level::SLIGHTLY_POSITIVE;
                             }
                         }
+                    }
 
+                    // Another farm would bring dear food down, which those short of money feel most
+                    if stats.food_prices.latest() > 1.5 {
+                        let shortfall = attributes.wallet.map_or(0.0, |wallet| {
+                            work::savings_shortfall(attributes.voter, wallet)
+                        });
+                        rating += (want_level::SLIGHTLY_POSITIVE as f64 * (1.0 + shortfall)).ceil()
+                            as i32;
+                    }
+
+                    // Growing what the town goes without balances diets, more so for those missing it
+                    let groups = &food_template.groups;
+                    if groups
+                        .iter()
+                        .any(|group| stats.lacking_food_groups.contains(group))
+                    {
+                        rating += want_level::SLIGHTLY_POSITIVE;
+                    }
+                    if let Some(nutrition) = attributes.nutrition {
+                        let lacking = nutrition.lacking();
+                        if groups.iter().any(|group| lacking.contains(group)) {
+                            rating += want_level::SLIGHTLY_POSITIVE;
+                        }
+                    }
+                    if groups
+                        .iter()
+                        .any(|group| stats.excess_food_groups.contains(group))
+                    {
+                        rating -= want_level::SLIGHTLY_POSITIVE;
+                    }
+
+                    // Nothing makes up for a farm growing food they won't eat
+                    if let Some(food_preferences) = attributes.food_preferences {
                         if !food_preferences.will_eat(&food_template.get_food()) {
                             rating = want_level::EXTREMELY_NEGATIVE;
                         }
@@ -306,6 +477,17 @@ impl Election {
                         want_level::NEUTRAL
                     }
                 }
+                ElectionOption::Granary => {
+                    let waste = stats.food_waste();
+
+                    if waste > 0.3 {
+                        want_level::POSITIVE
+                    } else if waste > 0.1 {
+                        want_level::SLIGHTLY_POSITIVE
+                    } else {
+                        want_level::NEUTRAL
+                    }
+                }
                 ElectionOption::Mint => {
                     let filled_percentage: f64 = stats.hole_filled_capacity.average();
 
@@ -317,6 +499,30 @@ impl Election {
                         want_level::NEUTRAL
                     }
                 }
+                ElectionOption::SetTax { tax, from, to } => {
+                    let mut rating = want_level::NEUTRAL;
+
+                    // Raising taxes goes down easier with the treasury running dry, cutting them with it full
+                    let filled_percentage: f64 = stats.hole_filled_capacity.average();
+                    if (to > from && filled_percentage < 0.3)
+                        || (to < from && filled_percentage > 0.7)
+                    {
+                        rating += want_level::SLIGHTLY_POSITIVE;
+                    }
+
+                    // What it would cost them each collection against what they have
+                    if let Some(wallet) = attributes.wallet {
+                        let change = tax.bill(*to, wallet) - tax.bill(*from, wallet);
+                        let share = change / wallet.money.max(work::SAVINGS_TARGET / 10.0);
+                        let care = attributes.voter.money_care.max(0) as f64 * share;
+                        rating -= care.ceil().clamp(
+                            want_level::EXTREMELY_NEGATIVE as f64,
+                            want_level::EXTREMELY_POSITIVE as f64,
+                        ) as i32;
+                    }
+
+                    rating
+                }
                 ElectionOption::House(_dwellings) => {
                     let mut rating = want_level::NEUTRAL;
 
@@ -345,11 +551,27 @@ impl Election {
         for option in &mut option_ratings {
             let death_count = match self.options[option.option_index] {
                 ElectionOption::DoNothing => 0,
-                ElectionOption::MakeFarm(_) => stats.deaths.get(&DeathReason::Starvation),
+                ElectionOption::MakeFarm(ref food_template) => {
+                    let balances_diet = food_template
+                        .groups
+                        .iter()
+                        .any(|group| stats.lacking_food_groups.contains(group));
+
+                    stats.deaths.get(&DeathReason::Starvation)
+                        + if balances_diet {
+                            stats.deaths.get(&DeathReason::Malnutrition)
+                        } else {
+                            0
+                        }
+                }
                 // Voties can't die form horniness but maybe they should
                 ElectionOption::MakeRz => 0,
                 // People don't die directly from running out of money
-                ElectionOption::MoneyHole | ElectionOption::Mint => 0,
+                ElectionOption::MoneyHole
+                | ElectionOption::Mint
+                | ElectionOption::SetTax { .. } => 0,
+                // Food that spoiled was never going to be eaten
+                ElectionOption::Granary => 0,
                 //
                 ElectionOption::House(_) => stats.deaths.get(&DeathReason::Homeliness),
             };
@@ -362,19 +584,54 @@ impl Election {
             option.rating += care.ceil() as i32;
         }
 
+        // Those short of money want work, all the more so when every job is taken
+        if let Some(wallet) = attributes.wallet {
+            let shortfall = work::savings_shortfall(attributes.voter, wallet);
+            let scarcity = if stats.jobs_filled.latest() > 0.9 {
+                2.0
+            } else {
+                1.0
+            };
+            for option in &mut option_ratings {
+                if let Some(job) = self.options[option.option_index].job() {
+                    let positions = job.positions() as f64 / Job::Minting.positions() as f64;
+                    let care =
+                        want_level::SLIGHTLY_POSITIVE as f64 * shortfall * scarcity * positions;
+                    option.rating += care.ceil() as i32;
+                }
+            }
+        }
+
+        // Nobody wants what the town can't pay for and money minded voters shy away from pricey options
+        let money = stats.money.latest();
+        for option in &mut option_ratings {
+            let cost = match self.options[option.option_index].building() {
+                Some(building) => building.construction_cost(),
+                None => continue,
+            };
+
+            if cost > money {
+                option.rating += want_level::NEGATIVE;
+            } else {
+                let share_of_treasury = cost / money;
+                let care = attributes.voter.money_care.max(0) as f64 * share_of_treasury;
+                option.rating -= care.ceil() as i32;
+            }
+        }
+
         // Apply modifiers
         for option in &mut option_ratings {
             option.rating = modify_rating(
                 rng,
                 option.rating,
                 &self.options[option.option_index],
-                &attributes,
+                attributes,
             );
         }
 
         option_ratings.sort_by(|a, b| a.rating.cmp(&b.rating).reverse());
 
-        self.votes.insert(voter, option_ratings);
+        option_ratings
     }
 }
 
@@ -384,16 +641,19 @@ pub struct ElectionBundle {
     pub election: Election,
 }
 
-#[derive(Debug, Resource)]
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
 pub struct ElectionTimer(pub Timer);
 
 pub fn create_election(
     commands: &mut Commands,
     election_type: ElectionType,
+    open_for: Duration,
     rng: &mut impl rand::Rng,
     food_collection: &FoodCollection,
+    tax_policy: &TaxPolicy,
 ) {
-    let options = get_options(rng, &food_collection);
+    let options = get_options(rng, &food_collection, tax_policy);
+    let seats = rng.gen_range(MIN_SEATS..=MAX_SEATS).min(options.len());
 
     info!("About to create an election {:?}", options);
 
@@ -402,44 +662,81 @@ pub fn create_election(
         election: Election {
             options,
             election_type,
+            seats,
             votes: default(),
             time_open: default(),
+            open_for,
+            electorate: default(),
+            abstained: default(),
+            sincere_votes: default(),
+            poll: None,
         },
     });
 }
 
 pub fn start_election_system(
     mut commands: Commands,
-    time: Res<Time>,
     sim_time: Res<SimTime>,
     mut rng: ResMut<rng::Rng>,
     mut timer: ResMut<ElectionTimer>,
     food_collection: Res<FoodCollection>,
+    tax_policy: Res<TaxPolicy>,
+    scenario: Res<Scenario>,
 ) {
-    if !timer.0.tick(sim_time.delta(&time)).just_finished() {
+    if !timer.0.tick(sim_time.delta()).just_finished() {
         return;
     }
 
-    let election = ElectionType::iter().choose(&mut rng.inner).unwrap();
+    let election = *scenario
+        .elections
+        .types
+        .iter()
+        .choose(&mut rng.inner)
+        .unwrap();
+
+    create_election(
+        &mut commands,
+        election,
+        Duration::from_secs_f32(scenario.elections.open_seconds),
+        &mut rng.inner,
+        &food_collection,
+        &tax_policy,
+    );
+}
 
-    create_election(&mut commands, election, &mut rng.inner, &food_collection);
+// Everything carrying out an election's winners touches
+#[derive(SystemParam)]
+pub struct Enactment<'w> {
+    asset_server: Res<'w, AssetServer>,
+    plots: ResMut<'w, BuildingPlots>,
+    rng: ResMut<'w, rng::Rng>,
+    treasury: ResMut<'w, Treasury>,
+    construction_queue: ResMut<'w, ConstructionQueue>,
+    tax_policy: ResMut<'w, TaxPolicy>,
 }
 
 pub fn close_elections_system(
     mut commands: Commands,
-    time: Res<Time>,
     sim_time: Res<SimTime>,
     mut election_history: ResMut<ElectionHistory>,
-    asset_server: Res<AssetServer>,
-    mut plots: ResMut<BuildingPlots>,
-    mut rng: ResMut<rng::Rng>,
+    enactment: Enactment,
     mut closed_election_events: EventWriter<ElectionClosedEvent>,
     mut query: Query<(Entity, &mut Election, &name::Name)>,
+    mut voters: Query<(Entity, &mut Voter, &Age, Option<&RequiresHouse>)>,
 ) {
+    let Enactment {
+        asset_server,
+        mut plots,
+        mut rng,
+        mut treasury,
+        mut construction_queue,
+        mut tax_policy,
+    } = enactment;
+
     for (entity, mut election, name) in &mut query {
-        election.time_open += sim_time.delta(&time);
+        election.time_open += sim_time.delta();
 
-        if election.time_open <= Duration::from_secs(15) {
+        if election.time_open <= election.open_for {
             continue;
         }
 
@@ -449,6 +746,20 @@ pub fn close_elections_system(
         }
 
         let result = election.result();
+        let winners = result.get_winners();
+
+        // Anyone who never got round to deciding counts as not having voted
+        for (voter, mut voter_info, age, requires_house) in &mut voters {
+            election
+                .electorate
+                .entry(voter)
+                .or_insert_with(|| Demographic::new(age, requires_house));
+
+            if let Some(won) = turnout::top_choice_won(&election, voter, &winners) {
+                voter_info.last_vote_won = Some(won);
+            }
+        }
+
         info!("Election result: {:?}", result);
         for election_type in ElectionType::iter() {
             info!(
@@ -458,37 +769,55 @@ pub fn close_elections_system(
             );
         }
 
-        match result.get_winner() {
-            ElectionOption::MakeFarm(food_template) => Building::Farm(food_template.clone()).build(
-                &mut commands,
-                &asset_server,
-                &mut plots,
-                &mut rng.inner,
-            ),
-            ElectionOption::MakeRz => Building::ReproductiveZone.build(
-                &mut commands,
-                &asset_server,
-                &mut plots,
-                &mut rng.inner,
-            ),
-            ElectionOption::MoneyHole => {
-                Building::MoneyHole.build(&mut commands, &asset_server, &mut plots, &mut rng.inner)
-            }
-            ElectionOption::Mint => {
-                Building::Mint.build(&mut commands, &asset_server, &mut plots, &mut rng.inner)
-            }
-            ElectionOption::House(dwellings) => Building::House(*dwellings).build(
-                &mut commands,
-                &asset_server,
-                &mut plots,
-                &mut rng.inner,
-            ),
-            ElectionOption::DoNothing => {
-                info!("Apathy won!")
+        // Winners are built in the order they won, ones the treasury can't pay for are handled by
+        // the construction policy
+        let mut construction = vec![];
+        let mut tried = vec![];
+        let mut fell_through = vec![];
+        let mut winners = result
+            .get_winners()
+            .into_iter()
+            .cloned()
+            .collect::<VecDeque<_>>();
+        while let Some(winner) = winners.pop_front() {
+            let outcome = match winner {
+                // Policy costs nothing up front so it always goes through
+                ElectionOption::SetTax { tax, to, .. } => {
+                    tax_policy.set_rate(tax, to);
+                    ConstructionOutcome::Enacted { tax, rate: to }
+                }
+                _ => construction::construct(
+                    &mut commands,
+                    &asset_server,
+                    &mut plots,
+                    &mut rng.inner,
+                    &mut treasury,
+                    &mut construction_queue,
+                    &winner,
+                ),
+            };
+            info!("{}: {}", winner.to_string(), outcome.to_string());
+
+            tried.push(winner.clone());
+            if let ConstructionOutcome::FellThrough(_) = outcome {
+                fell_through.push(winner);
+
+                // The next ranked option is whoever wins once the unaffordable ones are gone
+                let next = election
+                    .result_excluding(&fell_through)
+                    .get_winners()
+                    .into_iter()
+                    .find(|option| !tried.contains(option) && !winners.contains(option))
+                    .cloned();
+                if let Some(next) = next {
+                    winners.push_front(next);
+                }
             }
-        };
 
-        let held_election = HeldElection::new(name.0.as_str(), election.clone());
+            construction.push(outcome);
+        }
+
+        let held_election = HeldElection::new(name.0.as_str(), election.clone(), construction);
         closed_election_events.send(ElectionClosedEvent {
             held_election: held_election.clone(),
         });
@@ -524,7 +853,11 @@ struct WantTally {
     count: usize,
 }
 
-fn get_options(rng: &mut impl rand::Rng, food_collection: &FoodCollection) -> Vec<ElectionOption> {
+fn get_options(
+    rng: &mut impl rand::Rng,
+    food_collection: &FoodCollection,
+    tax_policy: &TaxPolicy,
+) -> Vec<ElectionOption> {
     let mut result: Vec<ElectionOption> = vec![];
 
     let dwellings_count = rng.gen_range(3..=10);
@@ -536,6 +869,18 @@ fn get_options(rng: &mut impl rand::Rng, food_collection: &FoodCollection) -> Ve
     ));
     result.push(ElectionOption::MoneyHole);
     result.push(ElectionOption::Mint);
+    result.push(ElectionOption::Granary);
+
+    // One tax is up for change each election
+    let tax = enum_iterator::all::<Tax>().choose(rng).unwrap();
+    let from = tax_policy.rate(tax);
+    let to = *tax
+        .rates()
+        .iter()
+        .filter(|rate| **rate != from)
+        .choose(rng)
+        .unwrap();
+    result.push(ElectionOption::SetTax { tax, from, to });
 
     result
 }
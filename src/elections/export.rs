@@ -0,0 +1,297 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bevy::prelude::*;
+use serde::Serialize;
+use voties::tally::{voting_methods::OptionRating, ElectionType};
+
+use crate::buildings::construction::ConstructionOutcome;
+
+use super::{
+    criteria::Criteria,
+    election::{ElectionHistory, ElectionOption, ElectionTypeResult, HeldElection},
+    satisfaction::{Leaderboard, Satisfaction},
+    turnout::Turnout,
+};
+
+pub const CSV_FILE_NAME: &str = "elections.csv";
+pub const LEADERBOARD_FILE_NAME: &str = "leaderboard.csv";
+
+// Present when held elections should be written out as they close
+#[derive(Debug, Clone, Resource)]
+pub struct ElectionExport {
+    pub dir: PathBuf,
+    // How many of the held elections have already been written
+    pub exported: usize,
+}
+
+impl ElectionExport {
+    /// Returns `None` unless `--export <dir>` was passed.
+    pub fn from_args(args: impl Iterator<Item = String>) -> Option<Self> {
+        let mut args = args.skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--export" {
+                let dir = args
+                    .next()
+                    .expect("--export requires a directory to write results to");
+                return Some(Self {
+                    dir: dir.into(),
+                    exported: 0,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ExportedBallot<'a> {
+    voter: Entity,
+    ratings: &'a Vec<OptionRating>,
+}
+
+// Everything about a held election, votes are listed in voter order rather than hash map order
+#[derive(Debug, Serialize)]
+struct ExportedElection<'a> {
+    name: &'a str,
+    election_type: ElectionType,
+    seats: usize,
+    open_for: Duration,
+    options: &'a Vec<ElectionOption>,
+    votes: Vec<ExportedBallot<'a>>,
+    // How strategic voters really felt
+    sincere_votes: Vec<ExportedBallot<'a>>,
+    winners: Vec<&'a ElectionOption>,
+    // The held method first then every other method over the same votes
+    results: &'a Vec<ElectionTypeResult>,
+    sincere_results: &'a Vec<ElectionTypeResult>,
+    construction: &'a Vec<ConstructionOutcome>,
+    turnout: &'a Turnout,
+    satisfaction: &'a Satisfaction,
+    criteria: &'a Criteria,
+}
+
+impl<'a> ExportedElection<'a> {
+    fn new(held_election: &'a HeldElection) -> Self {
+        let election = &held_election.election;
+        let mut votes = election
+            .votes
+            .iter()
+            .map(|(voter, ratings)| ExportedBallot {
+                voter: *voter,
+                ratings,
+            })
+            .collect::<Vec<_>>();
+        votes.sort_by_key(|ballot| ballot.voter);
+
+        let mut sincere_votes = election
+            .sincere_votes
+            .iter()
+            .map(|(voter, ratings)| ExportedBallot {
+                voter: *voter,
+                ratings,
+            })
+            .collect::<Vec<_>>();
+        sincere_votes.sort_by_key(|ballot| ballot.voter);
+
+        Self {
+            name: &held_election.name,
+            election_type: election.election_type,
+            seats: election.seats,
+            open_for: election.open_for,
+            options: &election.options,
+            votes,
+            sincere_votes,
+            winners: held_election.results[0].get_winners(),
+            results: &held_election.results,
+            sincere_results: &held_election.sincere_results,
+            construction: &held_election.construction,
+            turnout: &held_election.turnout,
+            satisfaction: &held_election.satisfaction,
+            criteria: &held_election.criteria,
+        }
+    }
+}
+
+// One row per election and method
+#[derive(Debug, Serialize)]
+struct ElectionRow<'a> {
+    election: usize,
+    name: &'a str,
+    held_type: String,
+    method: String,
+    // Whether this is the method the election was actually held under
+    held: bool,
+    seats: usize,
+    votes: usize,
+    eligible: usize,
+    // Voters who voted around the poll
+    strategic_voters: usize,
+    winners: String,
+    // Who would have won had everyone voted sincerely
+    sincere_winners: String,
+    vse: f64,
+    bayesian_regret: f64,
+    sincere_vse: f64,
+    construction: String,
+}
+
+#[derive(Debug, Serialize)]
+struct LeaderboardRow {
+    rank: usize,
+    method: String,
+    elections: usize,
+    mean_vse: f64,
+    mean_bayesian_regret: f64,
+    mean_sincere_vse: f64,
+}
+
+fn json_path(dir: &Path, index: usize, held_election: &HeldElection) -> PathBuf {
+    let name = held_election
+        .name
+        .replace(|c: char| !c.is_alphanumeric(), "_");
+    dir.join(format!("{:04}_{}.json", index, name))
+}
+
+fn write_json(dir: &Path, index: usize, held_election: &HeldElection) -> io::Result<()> {
+    let file = File::create(json_path(dir, index, held_election))?;
+    serde_json::to_writer_pretty(BufWriter::new(file), &ExportedElection::new(held_election))?;
+    Ok(())
+}
+
+fn write_csv_rows(path: &Path, index: usize, held_election: &HeldElection) -> io::Result<()> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(file);
+
+    let election = &held_election.election;
+    for ((result, sincere_result), score) in held_election
+        .results
+        .iter()
+        .zip(&held_election.sincere_results)
+        .zip(&held_election.satisfaction.scores)
+    {
+        let method = result.get_type();
+        let held = method == election.election_type;
+        writer.serialize(ElectionRow {
+            election: index,
+            name: &held_election.name,
+            held_type: election.election_type.to_string(),
+            method: method.to_string(),
+            held,
+            seats: election.seats,
+            votes: election.votes.len(),
+            eligible: held_election.turnout.eligible,
+            strategic_voters: election.sincere_votes.len(),
+            winners: result.winners_to_string(),
+            sincere_winners: sincere_result.winners_to_string(),
+            vse: score.vse,
+            bayesian_regret: score.bayesian_regret,
+            sincere_vse: score.sincere_vse,
+            // Only the held method's winners were ever built
+            construction: if held {
+                held_election.construction_to_string()
+            } else {
+                String::new()
+            },
+        })?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+// Starts the csv afresh so rows from an earlier run aren't mixed in
+fn write_csv_header(path: &Path) -> io::Result<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record([
+        "election",
+        "name",
+        "held_type",
+        "method",
+        "held",
+        "seats",
+        "votes",
+        "eligible",
+        "strategic_voters",
+        "winners",
+        "sincere_winners",
+        "vse",
+        "bayesian_regret",
+        "sincere_vse",
+        "construction",
+    ])?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+// Rewritten whole every time since every method's standing can change
+fn write_leaderboard(path: &Path, leaderboard: &Leaderboard) -> io::Result<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+    for (rank, entry) in leaderboard.ranked().into_iter().enumerate() {
+        writer.serialize(LeaderboardRow {
+            rank: rank + 1,
+            method: entry.election_type.to_string(),
+            elections: entry.elections,
+            mean_vse: entry.mean_vse(),
+            mean_bayesian_regret: entry.mean_bayesian_regret(),
+            mean_sincere_vse: entry.mean_sincere_vse(),
+        })?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+fn export(
+    export: &ElectionExport,
+    history: &ElectionHistory,
+    leaderboard: &Leaderboard,
+) -> io::Result<()> {
+    fs::create_dir_all(&export.dir)?;
+
+    let csv_path = export.dir.join(CSV_FILE_NAME);
+    if export.exported == 0 {
+        write_csv_header(&csv_path)?;
+    }
+
+    for (index, held_election) in history
+        .held_elections
+        .iter()
+        .enumerate()
+        .skip(export.exported)
+    {
+        write_json(&export.dir, index, held_election)?;
+        write_csv_rows(&csv_path, index, held_election)?;
+    }
+    write_leaderboard(&export.dir.join(LEADERBOARD_FILE_NAME), leaderboard)?;
+
+    Ok(())
+}
+
+pub fn export_held_elections_system(
+    mut export_config: ResMut<ElectionExport>,
+    history: Res<ElectionHistory>,
+    leaderboard: Res<Leaderboard>,
+) {
+    if history.held_elections.len() <= export_config.exported {
+        return;
+    }
+
+    if let Err(err) = export(&export_config, &history, &leaderboard) {
+        error!(
+            "Unable to export elections to {}: {}",
+            export_config.dir.display(),
+            err
+        );
+    }
+    // Not retried on failure, otherwise it would be logged every frame
+    export_config.exported = history.held_elections.len();
+}
@@ -1,10 +1,8 @@
-pub mod anti_plurality;
-pub mod approval;
+pub mod criteria;
 pub mod election;
-pub mod first_pass_the_post;
-pub mod good_ok_bad;
-pub mod preferential;
-pub mod star;
-pub mod usual_judgment;
+pub mod export;
+pub mod polling;
+pub mod satisfaction;
+pub mod strategy;
+pub mod turnout;
 pub mod voter;
-pub mod voting_methods;
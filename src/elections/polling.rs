@@ -0,0 +1,253 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, utils::HashMap};
+use rand::seq::IteratorRandom;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use voties::tally::{voting_methods::OptionRating, ElectionType};
+
+use crate::{
+    energy::Energy,
+    hunger::{FoodPreferences, Nutrition, Stomach},
+    money::Wallet,
+    name::Name,
+    reproduction::Reproductive,
+    rng,
+    save::{EntityRemap, RemapEntities},
+    shelter::RequiresHouse,
+    world_stats::WorldStats,
+};
+
+use super::{
+    election::{Election, ElectionHistory, ElectionOption, VoterAttributes},
+    strategy::Poll,
+    voter::Voter,
+};
+
+// How many people are asked each poll
+pub const POLL_SIZE: usize = 20;
+// Open elections are polled again after being open this much longer
+pub const POLL_INTERVAL: Duration = Duration::from_secs(5);
+// For a 95% confidence interval
+const Z_SCORE: f32 = 1.96;
+
+/// How far either side of `share` the real share could be, as a fraction.
+pub fn margin_of_error(share: f32, asked: usize, population: usize) -> f32 {
+    if asked == 0 {
+        return 1.0;
+    }
+
+    let standard_error = (share * (1.0 - share) / asked as f32).sqrt();
+    // Asking most of a small town leaves less room for error
+    let finite_population = if population > 1 {
+        ((population - asked.min(population)) as f32 / (population - 1) as f32).sqrt()
+    } else {
+        0.0
+    };
+
+    Z_SCORE * standard_error * finite_population
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptionShare {
+    pub option: ElectionOption,
+    // Of those asked, how many put it first
+    pub share: f32,
+    pub margin_of_error: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolledResult {
+    pub election_type: ElectionType,
+    pub winners: Vec<ElectionOption>,
+    // Of those asked, how many have one of the winners as their first choice
+    pub support: f32,
+    pub margin_of_error: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpinionPoll {
+    pub election: Entity,
+    // How long the election had been open when it was taken
+    pub taken_at: Duration,
+    pub asked: usize,
+    pub population: usize,
+    pub first_preferences: Vec<OptionShare>,
+    // Every method's result over the answers
+    pub results: Vec<PolledResult>,
+}
+
+impl OpinionPoll {
+    pub fn new(
+        election_entity: Entity,
+        election: &Election,
+        ballots: &[Vec<OptionRating>],
+        population: usize,
+    ) -> Self {
+        let asked = ballots.len();
+        let share_of = |favoured: &dyn Fn(&ElectionOption) -> bool| {
+            ballots
+                .iter()
+                .filter(|ballot| favoured(&election.options[ballot[0].option_index]))
+                .count() as f32
+                / asked as f32
+        };
+
+        let first_preferences = election
+            .options
+            .iter()
+            .map(|option| {
+                let share = share_of(&|favourite| favourite == option);
+                OptionShare {
+                    option: option.clone(),
+                    share,
+                    margin_of_error: margin_of_error(share, asked, population),
+                }
+            })
+            .collect();
+
+        let option_ratings = ballots.iter().collect::<Vec<_>>();
+        let results = ElectionType::iter()
+            .map(|election_type| {
+                let result =
+                    election_type.result(&election.options, &option_ratings, election.seats);
+                let winners = result
+                    .get_winners()
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let support = share_of(&|favourite| winners.contains(favourite));
+
+                PolledResult {
+                    election_type,
+                    winners,
+                    support,
+                    margin_of_error: margin_of_error(support, asked, population),
+                }
+            })
+            .collect();
+
+        Self {
+            election: election_entity,
+            taken_at: election.time_open,
+            asked,
+            population,
+            first_preferences,
+            results,
+        }
+    }
+
+    pub fn result_for(&self, election_type: ElectionType) -> Option<&PolledResult> {
+        self.results
+            .iter()
+            .find(|result| result.election_type == election_type)
+    }
+}
+
+impl RemapEntities for OpinionPoll {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        self.election = remap.get(self.election);
+    }
+}
+
+/// The latest poll of every open election.
+#[derive(Debug, Default, Resource)]
+pub struct OpinionPolls {
+    pub latest: HashMap<Entity, OpinionPoll>,
+}
+
+// Everything a voter's sincere ratings are worked out from, plus a name to ask them in order by
+type Respondent<'a> = (
+    &'a Name,
+    &'a Voter,
+    Option<&'a Energy>,
+    Option<&'a FoodPreferences>,
+    Option<&'a Reproductive>,
+    Option<&'a Stomach>,
+    Option<&'a RequiresHouse>,
+    Option<&'a Wallet>,
+    Option<&'a Nutrition>,
+);
+
+pub fn take_opinion_polls_system(
+    mut rng: ResMut<rng::Rng>,
+    stats: Res<WorldStats>,
+    election_history: Res<ElectionHistory>,
+    mut polls: ResMut<OpinionPolls>,
+    mut elections: Query<(Entity, &mut Election)>,
+    people: Query<Respondent>,
+) {
+    polls
+        .latest
+        .retain(|election, _| elections.contains(*election));
+
+    for (entity, mut election) in &mut elections {
+        let due = match polls.latest.get(&entity) {
+            Some(poll) => election.time_open >= poll.taken_at + POLL_INTERVAL,
+            None => true,
+        };
+        if !due && election.poll.is_some() {
+            continue;
+        }
+
+        // Asked in name order, a loaded world iterates people in a different order
+        let mut everyone = people.iter().collect::<Vec<_>>();
+        everyone.sort_by(|(a, ..), (b, ..)| a.0.cmp(&b.0));
+
+        let population = everyone.len();
+        let asked = everyone
+            .into_iter()
+            .choose_multiple(&mut rng.inner, POLL_SIZE);
+
+        // With nobody to ask strategic voters go off how the last election went
+        if asked.is_empty() {
+            if let (None, Some(previous)) = (&election.poll, election_history.held_elections.last())
+            {
+                election.poll = Some(Poll::from_previous(
+                    &election.options,
+                    &previous.election.options,
+                    &previous.election.get_option_ratings(),
+                ));
+            }
+            continue;
+        }
+
+        let ballots = asked
+            .into_iter()
+            .map(
+                |(
+                    _,
+                    voter,
+                    energy,
+                    food_preferences,
+                    reproductive,
+                    stomach,
+                    housing,
+                    wallet,
+                    nutrition,
+                )| {
+                    election.sincere_ratings(
+                        &mut rng.inner,
+                        &VoterAttributes {
+                            voter,
+                            energy,
+                            stomach,
+                            food_preferences,
+                            reproductive,
+                            housing,
+                            wallet,
+                            nutrition,
+                        },
+                        &stats,
+                    )
+                },
+            )
+            .collect::<Vec<_>>();
+
+        election.poll = Some(Poll::new(election.options.len(), &ballots));
+        polls.latest.insert(
+            entity,
+            OpinionPoll::new(entity, &election, &ballots, population),
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use bevy::{prelude::*, utils::HashMap};
+use serde::Serialize;
+use strum::IntoEnumIterator;
+use voties::tally::ElectionType;
+
+use super::election::{Election, ElectionHistory, ElectionTypeResult};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MethodScore {
+    pub election_type: ElectionType,
+    // Summed over every voter, averaged over the winners
+    pub winner_utility: f64,
+    // 1 when the best option won, 0 when no better than picking at random
+    pub vse: f64,
+    // Utility per voter lost by not picking the best option
+    pub bayesian_regret: f64,
+    // Vse had everyone voted sincerely
+    pub sincere_vse: f64,
+}
+
+/// How well each method served voters in one election, measured against how they really felt.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Satisfaction {
+    // Total utility of each option, indexed by option
+    pub option_utilities: Vec<f64>,
+    // In the same order as the held election's results
+    pub scores: Vec<MethodScore>,
+}
+
+impl Satisfaction {
+    pub fn new(
+        election: &Election,
+        results: &[ElectionTypeResult],
+        sincere_results: &[ElectionTypeResult],
+    ) -> Self {
+        let ballots = election.sincere_option_ratings();
+        let mut option_utilities = vec![0.0; election.options.len()];
+        for ballot in &ballots {
+            for rating in ballot.iter() {
+                option_utilities[rating.option_index] += rating.rating as f64;
+            }
+        }
+
+        let mut sorted_utilities = option_utilities.clone();
+        sorted_utilities.sort_by(|a, b| b.total_cmp(a));
+        // Picking at random
+        let random_utility =
+            option_utilities.iter().sum::<f64>() / option_utilities.len().max(1) as f64;
+
+        let winner_utility = |result: &ElectionTypeResult| {
+            let winners = result.get_winners();
+            winners
+                .iter()
+                .map(|winner| {
+                    let index = election
+                        .options
+                        .iter()
+                        .position(|option| option == *winner)
+                        .unwrap();
+                    option_utilities[index]
+                })
+                .sum::<f64>()
+                / winners.len() as f64
+        };
+        // Multi winner methods are held up against the best options for as many seats as they filled
+        let best_utility = |result: &ElectionTypeResult| {
+            let seats = result.get_winners().len();
+            sorted_utilities.iter().take(seats).sum::<f64>() / seats as f64
+        };
+        let vse = |result: &ElectionTypeResult| {
+            let best = best_utility(result);
+            if best - random_utility <= f64::EPSILON {
+                return 1.0;
+            }
+            (winner_utility(result) - random_utility) / (best - random_utility)
+        };
+
+        let scores = results
+            .iter()
+            .zip(sincere_results)
+            .map(|(result, sincere_result)| MethodScore {
+                election_type: result.get_type(),
+                winner_utility: winner_utility(result),
+                vse: vse(result),
+                bayesian_regret: (best_utility(result) - winner_utility(result))
+                    / ballots.len().max(1) as f64,
+                sincere_vse: vse(sincere_result),
+            })
+            .collect();
+
+        Self {
+            option_utilities,
+            scores,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaderboardEntry {
+    pub election_type: ElectionType,
+    pub elections: usize,
+    pub total_vse: f64,
+    pub total_bayesian_regret: f64,
+    pub total_sincere_vse: f64,
+}
+
+impl LeaderboardEntry {
+    pub fn mean_vse(&self) -> f64 {
+        self.total_vse / self.elections.max(1) as f64
+    }
+
+    pub fn mean_bayesian_regret(&self) -> f64 {
+        self.total_bayesian_regret / self.elections.max(1) as f64
+    }
+
+    pub fn mean_sincere_vse(&self) -> f64 {
+        self.total_sincere_vse / self.elections.max(1) as f64
+    }
+}
+
+/// Every method's satisfaction summed over every election held so far.
+#[derive(Debug, Default, Resource)]
+pub struct Leaderboard {
+    entries: HashMap<ElectionType, LeaderboardEntry>,
+    // How many of the held elections have been added
+    pub counted: usize,
+}
+
+impl Leaderboard {
+    pub fn add(&mut self, satisfaction: &Satisfaction) {
+        for score in &satisfaction.scores {
+            let entry =
+                self.entries
+                    .entry(score.election_type)
+                    .or_insert_with(|| LeaderboardEntry {
+                        election_type: score.election_type,
+                        elections: 0,
+                        total_vse: 0.0,
+                        total_bayesian_regret: 0.0,
+                        total_sincere_vse: 0.0,
+                    });
+            entry.elections += 1;
+            entry.total_vse += score.vse;
+            entry.total_bayesian_regret += score.bayesian_regret;
+            entry.total_sincere_vse += score.sincere_vse;
+        }
+    }
+
+    /// Best mean VSE first.
+    pub fn ranked(&self) -> Vec<&LeaderboardEntry> {
+        let mut entries = ElectionType::iter()
+            .filter_map(|election_type| self.entries.get(&election_type))
+            .collect::<Vec<_>>();
+        // Stable so ties stay in method order
+        entries.sort_by(|a, b| b.mean_vse().total_cmp(&a.mean_vse()));
+        entries
+    }
+}
+
+pub fn update_leaderboard_system(
+    mut leaderboard: ResMut<Leaderboard>,
+    history: Res<ElectionHistory>,
+) {
+    if history.held_elections.len() <= leaderboard.counted {
+        return;
+    }
+
+    for held_election in history.held_elections.iter().skip(leaderboard.counted) {
+        leaderboard.add(&held_election.satisfaction);
+    }
+    leaderboard.counted = history.held_elections.len();
+}
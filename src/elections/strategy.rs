@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use voties::tally::{voting_methods::OptionRating, want_level, ElectionType};
+
+use super::election::ElectionOption;
+
+/// How the options stand in the latest poll, by first preferences.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Poll {
+    // Indexed by option
+    pub first_preferences: Vec<usize>,
+}
+
+impl Poll {
+    pub fn new(option_count: usize, ballots: &[Vec<OptionRating>]) -> Self {
+        let mut first_preferences = vec![0; option_count];
+        for ballot in ballots {
+            if let Some(first) = ballot.first() {
+                first_preferences[first.option_index] += 1;
+            }
+        }
+
+        Self { first_preferences }
+    }
+
+    /// Polls a past election's ballots on the options it shared with this one.
+    pub fn from_previous(
+        options: &[ElectionOption],
+        previous_options: &[ElectionOption],
+        previous_ballots: &[&Vec<OptionRating>],
+    ) -> Self {
+        let mut first_preferences = vec![0; options.len()];
+        for ballot in previous_ballots {
+            // Their favourite of what is on offer this time
+            let first = ballot.iter().find_map(|rating| {
+                options
+                    .iter()
+                    .position(|option| *option == previous_options[rating.option_index])
+            });
+            if let Some(first) = first {
+                first_preferences[first] += 1;
+            }
+        }
+
+        Self { first_preferences }
+    }
+
+    /// The two options expected to fight it out, the leader first.
+    /// `None` unless the poll found two options anyone wanted.
+    pub fn front_runners(&self) -> Option<(usize, usize)> {
+        let mut standings = (0..self.first_preferences.len())
+            .filter(|i| self.first_preferences[*i] > 0)
+            .collect::<Vec<_>>();
+        // Ties go to the earlier option so the same front runners come out every run
+        standings.sort_by(|a, b| {
+            self.first_preferences[*b]
+                .cmp(&self.first_preferences[*a])
+                .then(a.cmp(b))
+        });
+
+        match standings[..] {
+            [leader, runner_up, ..] => Some((leader, runner_up)),
+            _ => None,
+        }
+    }
+}
+
+fn rating_of(option_ratings: &[OptionRating], option_index: usize) -> i32 {
+    option_ratings
+        .iter()
+        .find(|rating| rating.option_index == option_index)
+        .unwrap()
+        .rating
+}
+
+/// The ratings a strategic voter casts under `election_type` given how the poll says it'll go.
+/// Sincere ratings are given back untouched when there is nothing to gain.
+pub fn strategic_ratings(
+    election_type: ElectionType,
+    sincere: &[OptionRating],
+    poll: &Poll,
+) -> Vec<OptionRating> {
+    let mut ratings = sincere.to_vec();
+    let (leader, runner_up) = match poll.front_runners() {
+        Some(front_runners) => front_runners,
+        None => return ratings,
+    };
+
+    // Whichever front runner they like better is the one worth backing
+    let (ally, rival) = if rating_of(sincere, leader) >= rating_of(sincere, runner_up) {
+        (leader, runner_up)
+    } else {
+        (runner_up, leader)
+    };
+    let favourite = sincere.first().unwrap();
+    let least_favourite = sincere.last().unwrap();
+    let ally_rating = rating_of(sincere, ally);
+
+    match election_type {
+        // Compromise, a vote for someone who can't win is wasted
+        ElectionType::FirstPastThePost => {
+            if favourite.option_index != ally {
+                let rating = ratings
+                    .iter_mut()
+                    .find(|rating| rating.option_index == ally)
+                    .unwrap();
+                rating.rating = favourite.rating + 1;
+            }
+        }
+        // The one vote against goes to whoever could beat their side
+        ElectionType::AntiPlurality
+        // Bury the rival at the bottom of the ranking
+        | ElectionType::Preferential
+        | ElectionType::Schulze
+        | ElectionType::RankedPairs
+        | ElectionType::Copeland
+        | ElectionType::Minimax
+        | ElectionType::Borda
+        | ElectionType::Dowdall
+        | ElectionType::Nanson
+        | ElectionType::Baldwin
+        | ElectionType::Coombs
+        | ElectionType::SingleTransferableVote => {
+            if least_favourite.option_index != rival {
+                let rating = ratings
+                    .iter_mut()
+                    .find(|rating| rating.option_index == rival)
+                    .unwrap();
+                rating.rating = least_favourite.rating - 1;
+            }
+        }
+        // Min-max, everything at least as good as their front runner gets top marks and the rest nothing
+        ElectionType::GoodOkBad | ElectionType::Star | ElectionType::UsualJudgment => {
+            for rating in &mut ratings {
+                rating.rating = if rating.rating >= ally_rating {
+                    want_level::EXTREMELY_POSITIVE
+                } else {
+                    want_level::EXTREMELY_NEGATIVE
+                };
+            }
+        }
+        // Approve down to their front runner and no further
+        ElectionType::Approval | ElectionType::ProportionalApproval => {
+            for rating in &mut ratings {
+                rating.rating = if rating.rating >= ally_rating {
+                    rating.rating.max(want_level::SLIGHTLY_POSITIVE)
+                } else {
+                    rating.rating.min(want_level::SLIGHTLY_POSITIVE - 1)
+                };
+            }
+        }
+    }
+
+    ratings.sort_by(|a, b| a.rating.cmp(&b.rating).reverse());
+
+    ratings
+}
@@ -0,0 +1,160 @@
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    age::Age, death::OLD_AGE_DEATH_THRESHOLD, energy::Energy, hunger::Stomach,
+    shelter::RequiresHouse,
+};
+
+use super::{
+    election::{Election, ElectionOption},
+    voter::Voter,
+};
+
+// Even the least dutiful will sometimes turn up
+const MIN_CIVIC_DUTY_CHANCE: f32 = 0.3;
+// Walking this long to vote halves the chance of bothering
+const TRAVEL_SECONDS_HALF_CHANCE: f32 = 10.0;
+// Below this much energy or food people start putting off voting
+const STRUGGLING_FILLED: f32 = 0.3;
+// How much of the chance an empty stomach or no energy takes away
+const STRUGGLING_WEIGHT: f32 = 0.5;
+const LAST_VOTE_WON_MODIFIER: f32 = 1.1;
+const LAST_VOTE_LOST_MODIFIER: f32 = 0.75;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum AgeGroup {
+    Young,
+    Adult,
+    Elderly,
+}
+
+impl AgeGroup {
+    pub fn new(age: &Age) -> Self {
+        let third = OLD_AGE_DEATH_THRESHOLD / 3;
+        if age.duration_alive < third {
+            AgeGroup::Young
+        } else if age.duration_alive < third * 2 {
+            AgeGroup::Adult
+        } else {
+            AgeGroup::Elderly
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Demographic {
+    pub age_group: AgeGroup,
+    pub housed: bool,
+}
+
+impl Demographic {
+    pub fn new(age: &Age, requires_house: Option<&RequiresHouse>) -> Self {
+        Self {
+            age_group: AgeGroup::new(age),
+            // Anyone who doesn't need a house counts as housed
+            housed: !matches!(requires_house, Some(house) if house.shelter.is_none()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemographicTurnout {
+    pub demographic: Demographic,
+    pub eligible: usize,
+    pub voted: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Turnout {
+    // Everyone who could have voted, including those who died before it closed
+    pub eligible: usize,
+    pub voted: usize,
+    pub demographics: Vec<DemographicTurnout>,
+}
+
+impl Turnout {
+    pub fn new(election: &Election) -> Self {
+        let mut demographics = HashMap::<Demographic, DemographicTurnout>::default();
+        for (voter, demographic) in &election.electorate {
+            let turnout = demographics
+                .entry(*demographic)
+                .or_insert(DemographicTurnout {
+                    demographic: *demographic,
+                    eligible: 0,
+                    voted: 0,
+                });
+            turnout.eligible += 1;
+            if election.votes.contains_key(voter) {
+                turnout.voted += 1;
+            }
+        }
+
+        let mut demographics = demographics.into_values().collect::<Vec<_>>();
+        demographics.sort_by_key(|turnout| turnout.demographic);
+
+        Self {
+            eligible: demographics.iter().map(|turnout| turnout.eligible).sum(),
+            voted: demographics.iter().map(|turnout| turnout.voted).sum(),
+            demographics,
+        }
+    }
+
+    pub fn percent(&self) -> f32 {
+        if self.eligible == 0 {
+            return 0.0;
+        }
+
+        self.voted as f32 / self.eligible as f32 * 100.0
+    }
+}
+
+pub struct TurnoutFactors<'a> {
+    pub voter: &'a Voter,
+    pub energy: &'a Energy,
+    pub stomach: Option<&'a Stomach>,
+    // How long it would take to walk to the closest voting center, `None` if there isn't one
+    pub travel_seconds: Option<f32>,
+}
+
+fn struggling_modifier(filled: f32) -> f32 {
+    let shortfall = (1.0 - filled / STRUGGLING_FILLED).clamp(0.0, 1.0);
+    1.0 - shortfall * STRUGGLING_WEIGHT
+}
+
+/// The chance between 0 and 1 that someone goes to vote rather than abstaining.
+pub fn chance_to_vote(factors: &TurnoutFactors) -> f32 {
+    let travel_seconds = match factors.travel_seconds {
+        Some(travel_seconds) => travel_seconds,
+        None => return 0.0,
+    };
+
+    let mut chance = MIN_CIVIC_DUTY_CHANCE
+        + (1.0 - MIN_CIVIC_DUTY_CHANCE) * factors.voter.civic_duty.clamp(0.0, 1.0);
+
+    chance *= 1.0 / (1.0 + travel_seconds / TRAVEL_SECONDS_HALF_CHANCE);
+
+    // Tired and hungry people have other things on their mind
+    chance *= struggling_modifier((factors.energy.current_kcal / factors.energy.max_kcal) as f32);
+    if let Some(stomach) = factors.stomach {
+        chance *= struggling_modifier(stomach.percent_filled() as f32);
+    }
+
+    chance *= match factors.voter.last_vote_won {
+        Some(true) => LAST_VOTE_WON_MODIFIER,
+        Some(false) => LAST_VOTE_LOST_MODIFIER,
+        None => 1.0,
+    };
+
+    chance.clamp(0.0, 1.0)
+}
+
+/// Whether the option someone rated highest is among the winners.
+pub fn top_choice_won(
+    election: &Election,
+    voter: Entity,
+    winners: &[&ElectionOption],
+) -> Option<bool> {
+    let top = election.votes.get(&voter)?.first()?;
+    Some(winners.contains(&&election.options[top.option_index]))
+}
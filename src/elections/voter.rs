@@ -1,14 +1,23 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use voties::tally::want_level;
 
-use super::election::want_level;
-
-#[derive(Debug, Default, Component, Clone, Copy)]
+#[derive(Debug, Default, Component, Clone, Copy, Serialize, Deserialize)]
 pub struct Voter {
     pub money_care: i32,
     pub food_care: i32,
     pub reproductive_care: i32,
     pub housing_care: i32,
     pub death_care: i32,
+    // How strongly someone feels they should vote, from 0 to 1
+    #[serde(default)]
+    pub civic_duty: f32,
+    // Whether their top choice won the last election they voted in
+    #[serde(default)]
+    pub last_vote_won: Option<bool>,
+    // Votes around the poll rather than for what they really want
+    #[serde(default)]
+    pub strategic: bool,
 }
 
 fn random_care_value(rng: &mut impl rand::Rng) -> i32 {
@@ -16,13 +25,16 @@ fn random_care_value(rng: &mut impl rand::Rng) -> i32 {
 }
 
 impl Voter {
-    pub fn new_random(rng: &mut impl rand::Rng) -> Self {
+    pub fn new_random(rng: &mut impl rand::Rng, strategic_fraction: f32) -> Self {
         Self {
             money_care: random_care_value(rng),
             food_care: random_care_value(rng),
             reproductive_care: random_care_value(rng),
             housing_care: random_care_value(rng),
             death_care: random_care_value(rng),
+            civic_duty: rng.gen_range(0.0..1.0),
+            last_vote_won: None,
+            strategic: rng.gen_bool(strategic_fraction.clamp(0.0, 1.0) as f64),
         }
     }
 }
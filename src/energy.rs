@@ -1,10 +1,11 @@
 use std::ops::Sub;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{sim_time::SimTime, world_stats};
 
-#[derive(Debug, Component, Clone, Copy)]
+#[derive(Debug, Component, Clone, Copy, Serialize, Deserialize)]
 pub struct Energy {
     pub current_kcal: f64,
     pub max_kcal: f64,
@@ -28,11 +29,11 @@ impl Energy {
     }
 }
 
-pub fn drain_energy_system(time: Res<Time>, sim_time: Res<SimTime>, mut query: Query<&mut Energy>) {
+pub fn drain_energy_system(sim_time: Res<SimTime>, mut query: Query<&mut Energy>) {
     for mut energy in &mut query {
         energy.use_kcal(
             measurements::Volume::from_milliliters(100.0).as_milliliters()
-                * sim_time.delta_seconds(&time) as f64,
+                * sim_time.delta_seconds() as f64,
         );
     }
 }
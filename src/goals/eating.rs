@@ -1,18 +1,31 @@
 use bevy::prelude::*;
 use bevy_enum_filter::prelude::*;
-
-use crate::{buildings::farm, collision, energy, hunger, movement, sim_time::SimTime};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{
+    buildings::{
+        farm,
+        index::{BuildingIndex, BuildingKind},
+    },
+    collision, energy, hunger,
+    money::Wallet,
+    movement,
+    save::{EntityRemap, RemapEntities},
+    sim_time::SimTime,
+    upkeep::UpkeepCost,
+};
 
 use super::Goals;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EatingState {
     food: hunger::Food,
     waiting: Timer,
     count: f64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum HungryState {
     FindingTarget,
     MovingToTarget(Entity),
@@ -25,9 +38,17 @@ impl Default for HungryState {
     }
 }
 
+impl RemapEntities for HungryState {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        if let HungryState::MovingToTarget(target) = self {
+            *target = remap.get(*target);
+        }
+    }
+}
+
 pub fn step_hunger_goal_system(
-    time: Res<Time>,
     sim_time: Res<SimTime>,
+    building_index: Res<BuildingIndex>,
     mut person: Query<
         (
             &mut Goals,
@@ -37,12 +58,30 @@ pub fn step_hunger_goal_system(
             &mut movement::MovementGoal,
             &collision::CollisionHolder,
             &mut hunger::Stomach,
+            &mut Wallet,
+            &mut hunger::Nutrition,
         ),
         With<Enum!(super::Goals::Hungry)>,
     >,
-    mut farms: Query<(Entity, &Transform, &hunger::Food, &mut farm::Farm)>,
+    mut farms: Query<(
+        Entity,
+        &Transform,
+        &hunger::Food,
+        &mut farm::Farm,
+        &mut UpkeepCost,
+    )>,
 ) {
-    for (mut goal, mut energy, position, food_pref, mut move_goal, col, mut stomach) in &mut person
+    for (
+        mut goal,
+        mut energy,
+        position,
+        food_pref,
+        mut move_goal,
+        col,
+        mut stomach,
+        mut wallet,
+        mut nutrition,
+    ) in &mut person
     {
         let hungry = match goal.clone() {
             Goals::Hungry(hungry) => hungry,
@@ -51,37 +90,78 @@ pub fn step_hunger_goal_system(
 
         match hungry {
             HungryState::FindingTarget => {
-                let mut closest_farm: Option<(Entity, Vec3)> = None;
-                let mut closest_distance = f32::MAX;
+                // Farms only making food groups they won't eat aren't worth looking at
+                let kinds: Vec<_> = hunger::FoodGroup::iter()
+                    .filter(|group| match food_pref {
+                        Some(food_pref) => !food_pref.wont_eat.contains(group),
+                        None => true,
+                    })
+                    .map(BuildingKind::Farm)
+                    .collect();
+
+                let sells_food = |farm_entity: Entity, check_price: bool| {
+                    let (_, _, food, farm, _) = match farms.get(farm_entity) {
+                        Ok(result) => result,
+                        Err(_) => return false,
+                    };
 
-                for (farm_entity, farm_position, food, farm) in &farms {
-                    if !stomach.can_eat(food) {
-                        continue;
+                    if !stomach.can_eat(food) || !farm.has_surplus() {
+                        return false;
                     }
-
-                    if !farm.has_surplus() {
-                        continue;
+                    if check_price && !farm.can_afford(&wallet) {
+                        return false;
                     }
 
-                    if let Some(food_pref) = food_pref {
-                        if !food_pref.will_eat(food) {
-                            continue;
-                        }
+                    match food_pref {
+                        Some(food_pref) => food_pref.will_eat(food),
+                        None => true,
                     }
+                };
 
-                    let distance = position
-                        .translation
-                        .distance_squared(farm_position.translation);
-
-                    if distance < closest_distance {
-                        closest_distance = distance;
-                        closest_farm = Some((farm_entity, farm_position.translation));
+                // An unbalanced diet sends them looking for what it's missing before the nearest meal
+                let lacking = nutrition.lacking();
+                let excess = nutrition.excess();
+                let balancing_kinds: Vec<_> = if lacking.is_empty() {
+                    kinds.clone()
+                } else {
+                    kinds
+                        .iter()
+                        .filter(|kind| match kind {
+                            BuildingKind::Farm(group) => lacking.contains(group),
+                            _ => false,
+                        })
+                        .copied()
+                        .collect()
+                };
+                let balances_diet = |farm_entity: Entity| match farms.get(farm_entity) {
+                    Ok((_, _, food, _, _)) => {
+                        !food.groups.iter().any(|group| excess.contains(group))
                     }
-                }
+                    Err(_) => false,
+                };
 
-                if let Some((farm_entity, trans)) = closest_farm {
-                    *goal = Goals::Hungry(HungryState::MovingToTarget(farm_entity));
-                    move_goal.target = Some(trans);
+                let closest_farm = if nutrition.is_balanced() {
+                    None
+                } else {
+                    building_index.nearest(&balancing_kinds, position.translation, |farm| {
+                        balances_diet(farm) && sells_food(farm, true)
+                    })
+                }
+                .or_else(|| {
+                    building_index
+                        .nearest(&kinds, position.translation, |farm| sells_food(farm, true))
+                });
+
+                if let Some(farm) = closest_farm {
+                    *goal = Goals::Hungry(HungryState::MovingToTarget(farm.entity));
+                    move_goal.target = Some(farm.translation);
+                } else if building_index
+                    .nearest(&kinds, position.translation, |farm| sells_food(farm, false))
+                    .is_some()
+                {
+                    // There is food but they can't pay for it, so off to earn some
+                    *goal = Goals::Work(default());
+                    continue;
                 } else {
                     *goal = Goals::None;
                     continue;
@@ -90,7 +170,7 @@ pub fn step_hunger_goal_system(
             HungryState::MovingToTarget(target) => {
                 // Farm reached
                 if col.colliding_with(target).is_some() {
-                    let (_, _, farm_food, mut farm_farm) = match farms.get_mut(target) {
+                    let (_, _, farm_food, mut farm_farm, mut upkeep) = match farms.get_mut(target) {
                         Ok(result) => result,
                         Err(_) => {
                             *goal = Goals::None;
@@ -109,8 +189,11 @@ pub fn step_hunger_goal_system(
                     let mut count = 0;
                     while energy.current_kcal + farm_food.kcal * (count as f64) < energy.max_kcal
                         && stomach.filled_ml + farm_food.ml * (count as f64) < stomach.max_size_ml
-                        && farm_farm.take_food()
                     {
+                        match farm_farm.sell(&mut wallet) {
+                            Some(paid) => upkeep.income += paid,
+                            None => break,
+                        }
                         count += 1;
                     }
 
@@ -122,13 +205,10 @@ pub fn step_hunger_goal_system(
                 }
             }
             HungryState::Eating(mut eating_state) => {
-                if eating_state
-                    .waiting
-                    .tick(sim_time.delta(&time))
-                    .just_finished()
-                {
+                if eating_state.waiting.tick(sim_time.delta()).just_finished() {
                     stomach.filled_ml += eating_state.food.ml * eating_state.count;
                     energy.current_kcal += eating_state.food.kcal * eating_state.count;
+                    nutrition.eat(&eating_state.food, eating_state.count);
                     *goal = Goals::None;
                 } else {
                     *goal = Goals::Hungry(HungryState::Eating(eating_state));
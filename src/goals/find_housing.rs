@@ -1,15 +1,21 @@
 use bevy::prelude::*;
 use bevy_enum_filter::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    buildings::{self, house::House},
+    buildings::{
+        self,
+        house::House,
+        index::{BuildingIndex, BuildingKind},
+    },
     collision, movement,
+    save::{EntityRemap, RemapEntities},
     shelter::RequiresHouse,
 };
 
 use super::Goals;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum HousingState {
     FindingHouse,
     MovingToHouse(Entity),
@@ -21,7 +27,16 @@ impl Default for HousingState {
     }
 }
 
+impl RemapEntities for HousingState {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        if let HousingState::MovingToHouse(target) = self {
+            *target = remap.get(*target);
+        }
+    }
+}
+
 pub fn step_find_housing_goal_system(
+    building_index: Res<BuildingIndex>,
     mut query: Query<
         (
             Entity,
@@ -48,25 +63,19 @@ pub fn step_find_housing_goal_system(
 
         match state {
             HousingState::FindingHouse => {
-                let mut closest_house: Option<(Entity, Vec3)> = None;
-                let mut closest_distance = f32::MAX;
-
-                for (entity, transform, house) in &housing {
-                    if house.is_full() {
-                        continue;
-                    }
-
-                    let distance = position.translation.distance_squared(transform.translation);
-
-                    if distance < closest_distance {
-                        closest_distance = distance;
-                        closest_house = Some((entity, transform.translation));
-                    }
-                }
+                // Someone may have moved in since the index last caught up
+                let closest_house = building_index.nearest(
+                    &[BuildingKind::House],
+                    position.translation,
+                    |entity| match housing.get(entity) {
+                        Ok((_, _, house)) => !house.is_full(),
+                        Err(_) => false,
+                    },
+                );
 
-                if let Some((entity, trans)) = closest_house {
-                    *goal = Goals::FindHousing(HousingState::MovingToHouse(entity));
-                    movement_goal.target = Some(trans);
+                if let Some(house) = closest_house {
+                    *goal = Goals::FindHousing(HousingState::MovingToHouse(house.entity));
+                    movement_goal.target = Some(house.translation);
                 } else {
                     *goal = Goals::None;
                     continue;
@@ -3,11 +3,17 @@ pub mod find_housing;
 pub mod reproducing;
 pub mod vote;
 pub mod wander;
+pub mod work;
 
 use bevy::prelude::*;
 use bevy_enum_filter::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::sets::LifeSet;
+use crate::{
+    save::{EntityRemap, RemapEntities},
+    sets::LifeSet,
+    sim_time::SimSchedule,
+};
 
 use self::{
     eating::{step_hunger_goal_system, HungryState},
@@ -15,9 +21,10 @@ use self::{
     reproducing::{step_reproduce_goal_system, ReproducingState},
     vote::{vote_goal_system, Vote},
     wander::{step_wander_goal_system, WanderState},
+    work::{step_work_goal_system, WorkState},
 };
 
-#[derive(Debug, Clone, Component, EnumFilter)]
+#[derive(Debug, Clone, Component, EnumFilter, Serialize, Deserialize)]
 pub enum Goals {
     None,
     Hungry(HungryState),
@@ -25,6 +32,20 @@ pub enum Goals {
     Wander(WanderState),
     Vote(Vote),
     FindHousing(HousingState),
+    Work(WorkState),
+}
+
+impl RemapEntities for Goals {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        match self {
+            Goals::None | Goals::Wander(_) => {}
+            Goals::Hungry(state) => state.remap_entities(remap),
+            Goals::Reproduce(state) => state.remap_entities(remap),
+            Goals::Vote(vote) => vote.remap_entities(remap),
+            Goals::FindHousing(state) => state.remap_entities(remap),
+            Goals::Work(state) => state.remap_entities(remap),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -32,12 +53,17 @@ pub struct GoalsPlugin;
 
 impl Plugin for GoalsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems((
-            step_hunger_goal_system.in_set(LifeSet::Goal),
-            step_reproduce_goal_system.in_set(LifeSet::Goal),
-            step_wander_goal_system.in_set(LifeSet::Goal),
-            vote_goal_system.in_set(LifeSet::Goal),
-            step_find_housing_goal_system.in_set(LifeSet::Goal),
-        ));
+        app.add_systems(
+            (
+                step_hunger_goal_system.in_set(LifeSet::Goal),
+                step_reproduce_goal_system.in_set(LifeSet::Goal),
+                step_wander_goal_system.in_set(LifeSet::Goal),
+                vote_goal_system.in_set(LifeSet::Goal),
+                step_find_housing_goal_system.in_set(LifeSet::Goal),
+                step_work_goal_system.in_set(LifeSet::Goal),
+            )
+                .chain()
+                .in_schedule(SimSchedule),
+        );
     }
 }
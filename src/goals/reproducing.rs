@@ -2,23 +2,26 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 use bevy_enum_filter::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
+    buildings::index::{BuildingIndex, BuildingKind},
     collision, movement,
     reproduction::{self, get_pregnancy_duration, get_reproduction_cooldown},
     rng,
+    save::{EntityRemap, RemapEntities},
     sim_time::SimTime,
 };
 
 use super::Goals;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WaitingAtRzState {
     waiting_timer: Timer,
     rz: Entity,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ReproducingState {
     FindingRZ,
     MovingToRz(Entity),
@@ -31,10 +34,20 @@ impl Default for ReproducingState {
     }
 }
 
+impl RemapEntities for ReproducingState {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        match self {
+            ReproducingState::FindingRZ => {}
+            ReproducingState::MovingToRz(rz) => *rz = remap.get(*rz),
+            ReproducingState::WaitingAtRz(waiting) => waiting.rz = remap.get(waiting.rz),
+        }
+    }
+}
+
 pub fn step_reproduce_goal_system(
     mut commands: Commands,
-    time: Res<Time>,
     sim_time: Res<SimTime>,
+    building_index: Res<BuildingIndex>,
     mut rng: ResMut<rng::Rng>,
     mut person: Query<
         (
@@ -62,21 +75,15 @@ pub fn step_reproduce_goal_system(
 
         match reproducing {
             ReproducingState::FindingRZ => {
-                let mut closest_rz: Option<(Entity, Vec3)> = None;
-                let mut closest_distance = f32::MAX;
-
-                for (rz_entity, rz_trans, _, _) in &reproductive_zones_q {
-                    let distance = trans.translation.distance_squared(rz_trans.translation);
-
-                    if distance < closest_distance {
-                        closest_distance = distance;
-                        closest_rz = Some((rz_entity, rz_trans.translation));
-                    }
-                }
-
-                if let Some((rz_entity, trans)) = closest_rz {
-                    *goal = Goals::Reproduce(ReproducingState::MovingToRz(rz_entity));
-                    move_goal.target = Some(trans);
+                let closest_rz = building_index.nearest(
+                    &[BuildingKind::ReproductiveZone],
+                    trans.translation,
+                    |rz_entity| reproductive_zones_q.contains(rz_entity),
+                );
+
+                if let Some(rz) = closest_rz {
+                    *goal = Goals::Reproduce(ReproducingState::MovingToRz(rz.entity));
+                    move_goal.target = Some(rz.translation);
                 } else {
                     *goal = Goals::None;
                     continue;
@@ -92,7 +99,7 @@ pub fn step_reproduce_goal_system(
                 }
             }
             ReproducingState::WaitingAtRz(mut waiting) => {
-                waiting.waiting_timer.tick(sim_time.delta(&time));
+                waiting.waiting_timer.tick(sim_time.delta());
 
                 if waiting.waiting_timer.finished() {
                     *goal = Goals::None;
@@ -1,24 +1,30 @@
 use bevy::prelude::*;
 use bevy_enum_filter::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    buildings::voting_center::VotingCenter,
+    buildings::{
+        index::{BuildingIndex, BuildingKind},
+        voting_center::VotingCenter,
+    },
     elections::{
         election::{Election, VoterAttributes},
         voter::Voter,
     },
     energy::Energy,
-    hunger::{FoodPreferences, Stomach},
+    hunger::{FoodPreferences, Nutrition, Stomach},
+    money::Wallet,
     movement,
     reproduction::Reproductive,
     rng,
+    save::{EntityRemap, RemapEntities},
     shelter::RequiresHouse,
     world_stats,
 };
 
 use super::Goals;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum VoteState {
     FindingVotingCenter,
     MovingToTarget,
@@ -31,7 +37,7 @@ impl Default for VoteState {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Vote {
     state: VoteState,
     target_election: Entity,
@@ -53,8 +59,15 @@ impl Vote {
     }
 }
 
+impl RemapEntities for Vote {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        self.target_election = remap.get(self.target_election);
+    }
+}
+
 pub fn vote_goal_system(
     stats: Res<world_stats::WorldStats>,
+    building_index: Res<BuildingIndex>,
     mut rng: ResMut<rng::Rng>,
     mut query: Query<
         (
@@ -68,6 +81,8 @@ pub fn vote_goal_system(
             Option<&Reproductive>,
             Option<&Stomach>,
             Option<&RequiresHouse>,
+            Option<&Wallet>,
+            Option<&Nutrition>,
         ),
         With<Enum!(super::Goals::Vote)>,
     >,
@@ -85,6 +100,8 @@ pub fn vote_goal_system(
         reproductive,
         stomach,
         requires_house,
+        wallet,
+        nutrition,
     ) in &mut query
     {
         let vote = match goal.clone() {
@@ -94,19 +111,14 @@ pub fn vote_goal_system(
 
         match vote.state {
             VoteState::FindingVotingCenter => {
-                let mut closest_voting_center: Option<Vec3> = None;
-                let mut closest_distance = f32::MAX;
-
-                for transform in &voting_centers {
-                    let distance = position.translation.distance_squared(transform.translation);
-
-                    if distance < closest_distance {
-                        closest_distance = distance;
-                        closest_voting_center = Some(transform.translation);
-                    }
-                }
+                let closest_voting_center = building_index.nearest(
+                    &[BuildingKind::VotingCenter],
+                    position.translation,
+                    |entity| voting_centers.contains(entity),
+                );
 
-                if let Some(trans) = closest_voting_center {
+                if let Some(voting_center) = closest_voting_center {
+                    let trans = voting_center.translation;
                     *goal = Goals::Vote(vote.new_state(VoteState::MovingToTarget));
                     movement_goal.target =
                         Some(Vec3::new(trans.x, trans.y, position.translation.z));
@@ -139,6 +151,8 @@ pub fn vote_goal_system(
                         food_preferences,
                         reproductive,
                         housing: requires_house,
+                        wallet,
+                        nutrition,
                     },
                     &stats,
                 );
@@ -1,12 +1,13 @@
 use bevy::prelude::*;
 use bevy_enum_filter::prelude::*;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
 use crate::{movement, rng};
 
 use super::Goals;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum WanderState {
     FindingTarget,
     MovingToTarget,
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use bevy_enum_filter::prelude::*;
+use serde::{Deserialize, Serialize};
+use voties::tally::want_level;
+
+use crate::{
+    buildings::{
+        self,
+        index::{BuildingIndex, BuildingKind},
+        workplace::{Workplace, SHIFT_LENGTH},
+    },
+    elections::voter::Voter,
+    money::{Money, Treasury, Wallet},
+    movement,
+    save::{EntityRemap, RemapEntities},
+    sim_time::SimTime,
+};
+
+use super::Goals;
+
+// What someone who cares a middling amount about money likes to have put by
+pub const SAVINGS_TARGET: Money = 500.0;
+// Even those who don't care about money keep enough by for a few meals
+pub const MIN_SAVINGS: Money = 150.0;
+
+/// How much someone wants in their wallet before they stop looking for work.
+pub fn savings_target(voter: &Voter) -> Money {
+    let care = (voter.money_care - want_level::EXTREMELY_NEGATIVE) as Money
+        / (want_level::EXTREMELY_POSITIVE - want_level::EXTREMELY_NEGATIVE) as Money;
+    MIN_SAVINGS.max(SAVINGS_TARGET * 2.0 * care.clamp(0.0, 1.0))
+}
+
+/// How far short of their savings target someone is, from 0 when they have enough to 1 when broke.
+pub fn savings_shortfall(voter: &Voter, wallet: &Wallet) -> f64 {
+    let target = savings_target(voter);
+    if target <= 0.0 {
+        return 0.0;
+    }
+
+    ((target - wallet.money) / target).clamp(0.0, 1.0)
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum WorkState {
+    #[default]
+    FindingWork,
+    MovingToWork(Entity),
+    Working {
+        workplace: Entity,
+        shift: Timer,
+    },
+}
+
+impl RemapEntities for WorkState {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        match self {
+            WorkState::FindingWork => {}
+            WorkState::MovingToWork(target) => *target = remap.get(*target),
+            WorkState::Working { workplace, .. } => *workplace = remap.get(*workplace),
+        }
+    }
+}
+
+pub fn step_work_goal_system(
+    sim_time: Res<SimTime>,
+    building_index: Res<BuildingIndex>,
+    mut treasury: ResMut<Treasury>,
+    mut query: Query<
+        (
+            &mut Goals,
+            &mut movement::MovementGoal,
+            &Transform,
+            &mut Wallet,
+        ),
+        With<Enum!(super::Goals::Work)>,
+    >,
+    mut workplaces: Query<
+        &mut Workplace,
+        With<Enum!(buildings::building::BuildingStatus::Operational)>,
+    >,
+) {
+    for (mut goal, mut movement_goal, position, mut wallet) in &mut query {
+        // The query's enum filter only lets through people whose goal is work
+        let Goals::Work(state) = goal.clone() else {
+            unreachable!("step_work_goal_system matched a goal other than work");
+        };
+
+        match state {
+            WorkState::FindingWork => {
+                let closest_workplace = building_index.nearest(
+                    &[BuildingKind::Workplace],
+                    position.translation,
+                    |entity| match workplaces.get(entity) {
+                        Ok(workplace) => workplace.has_opening(),
+                        Err(_) => false,
+                    },
+                );
+
+                if let Some(workplace) = closest_workplace {
+                    // Held for them now so others looking this tick go elsewhere
+                    workplaces.get_mut(workplace.entity).unwrap().taken += 1;
+
+                    let trans = workplace.translation;
+                    *goal = Goals::Work(WorkState::MovingToWork(workplace.entity));
+                    movement_goal.target =
+                        Some(Vec3::new(trans.x, trans.y, position.translation.z));
+                } else {
+                    *goal = Goals::None;
+                    continue;
+                }
+            }
+            WorkState::MovingToWork(target) => {
+                // Closed down on the way there
+                if !workplaces.contains(target) {
+                    *goal = Goals::None;
+                    continue;
+                }
+
+                if position.translation.distance(movement_goal.target.unwrap()) < 10.0 {
+                    *goal = Goals::Work(WorkState::Working {
+                        workplace: target,
+                        shift: Timer::new(SHIFT_LENGTH, TimerMode::Once),
+                    });
+                }
+            }
+            WorkState::Working {
+                workplace,
+                mut shift,
+            } => {
+                let wage = match workplaces.get(workplace) {
+                    Ok(building) => building.job.wage(),
+                    Err(_) => {
+                        *goal = Goals::None;
+                        continue;
+                    }
+                };
+
+                if shift.tick(sim_time.delta()).just_finished() {
+                    // The shift goes unpaid when the treasury can't cover it
+                    if treasury.spend(wage) {
+                        wallet.earn(wage);
+                    }
+                    *goal = Goals::None;
+                } else {
+                    *goal = Goals::Work(WorkState::Working { workplace, shift });
+                }
+            }
+        }
+    }
+}
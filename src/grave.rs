@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     age::Age,
@@ -7,7 +8,7 @@ use crate::{
     sim_time::SimTime,
 };
 
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct Grave {
     pub created: std::time::Duration,
     pub name: String,
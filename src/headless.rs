@@ -0,0 +1,249 @@
+use std::{path::PathBuf, time::Duration};
+
+use bevy::{app::AppExit, ecs::system::SystemParam, prelude::*, utils::HashMap};
+use strum::IntoEnumIterator;
+use voties::tally::ElectionType;
+
+use crate::{
+    death::DeathReason,
+    elections::{election::ElectionHistory, satisfaction::Leaderboard},
+    money::Treasury,
+    save::SaveWorldEvent,
+    sets::AppState,
+    sim_time::SimTime,
+    tax::{TaxCollection, TaxPolicy},
+    world_stats::WorldStats,
+};
+
+const DEFAULT_ELECTION_LIMIT: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunLimit {
+    SimTime(Duration),
+    Elections(usize),
+}
+
+#[derive(Debug, Clone, Resource)]
+pub struct HeadlessConfig {
+    pub run_limit: RunLimit,
+    // Where to save the world once the run is over
+    pub save: Option<PathBuf>,
+}
+
+impl HeadlessConfig {
+    /// Returns `None` unless `--headless` was passed.
+    /// Accepts `--seconds <N>` or `--elections <N>` to bound the run
+    /// and `--save <path>` to keep the world it ends with.
+    pub fn from_args(args: impl Iterator<Item = String>) -> Option<Self> {
+        let mut headless = false;
+        let mut run_limit = RunLimit::Elections(DEFAULT_ELECTION_LIMIT);
+        let mut save = None;
+
+        let mut args = args.skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--headless" => headless = true,
+                "--seconds" => {
+                    let seconds: u64 = args
+                        .next()
+                        .and_then(|value| value.parse().ok())
+                        .expect("--seconds requires a whole number of seconds");
+                    run_limit = RunLimit::SimTime(Duration::from_secs(seconds));
+                }
+                "--elections" => {
+                    let elections: usize = args
+                        .next()
+                        .and_then(|value| value.parse().ok())
+                        .expect("--elections requires a whole number of elections");
+                    run_limit = RunLimit::Elections(elections);
+                }
+                "--save" => {
+                    let path = args.next().expect("--save requires a path to save to");
+                    save = Some(path.into());
+                }
+                _ => {}
+            }
+        }
+
+        headless.then_some(Self { run_limit, save })
+    }
+}
+
+#[derive(Debug)]
+pub struct HeadlessPlugin {
+    pub config: HeadlessConfig,
+}
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .add_system(skip_ui_setup.run_if(in_state(AppState::SettingUpUi)))
+            .add_system(check_run_finished_system.run_if(in_state(AppState::Running)));
+    }
+}
+
+// There is no UI to build so go straight to running as fast as the clock allows
+fn skip_ui_setup(mut state: ResMut<NextState<AppState>>, mut sim_time: ResMut<SimTime>) {
+    sim_time.unthrottle();
+    state.set(AppState::Running);
+}
+
+// Everything the summary at the end of a run is written from
+#[derive(SystemParam)]
+struct RunSummary<'w> {
+    sim_time: Res<'w, SimTime>,
+    election_history: Res<'w, ElectionHistory>,
+    treasury: Res<'w, Treasury>,
+    tax_policy: Res<'w, TaxPolicy>,
+    tax_collection: Res<'w, TaxCollection>,
+    stats: Res<'w, WorldStats>,
+}
+
+fn check_run_finished_system(
+    config: Res<HeadlessConfig>,
+    run: RunSummary,
+    mut save_events: EventWriter<SaveWorldEvent>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let finished = match config.run_limit {
+        RunLimit::SimTime(limit) => run.sim_time.elapsed() >= limit,
+        RunLimit::Elections(limit) => run.election_history.held_elections.len() >= limit,
+    };
+
+    if !finished {
+        return;
+    }
+
+    println!("{}", summary(&run));
+
+    if let Some(path) = &config.save {
+        save_events.send(SaveWorldEvent { path: path.clone() });
+    }
+    exit.send(AppExit);
+}
+
+fn summary(run: &RunSummary) -> String {
+    let RunSummary {
+        sim_time,
+        election_history,
+        treasury,
+        tax_policy,
+        tax_collection,
+        stats,
+    } = run;
+    let mut str = string_builder::Builder::default();
+
+    str.append(format!(
+        "Simulated {:.1}s over {} ticks\n",
+        sim_time.elapsed().as_secs_f32(),
+        sim_time.ticks()
+    ));
+    str.append(format!("Treasury: ${:.2}\n", treasury.money));
+    str.append(format!(
+        "Taxes: {} - ${:.2} collected\n",
+        **tax_policy, tax_collection.collected
+    ));
+    str.append(format!("Population: {}\n", stats.population.latest()));
+    str.append(format!(
+        "Food spoiled: {} servings - {:.0}% wasted lately\n",
+        stats.food_spoiled_total,
+        stats.food_waste() * 100.0
+    ));
+    str.append(format!(
+        "Diet: {:.0}% malnourished - lacking {:?}, too much {:?}\n",
+        stats.malnourished.latest() * 100.0,
+        stats.lacking_food_groups,
+        stats.excess_food_groups
+    ));
+
+    str.append("Deaths (last 60s):\n");
+    for death_reason in enum_iterator::all::<DeathReason>() {
+        str.append(format!(
+            "  {:?}: {}\n",
+            death_reason,
+            stats.deaths.get(&death_reason)
+        ));
+    }
+
+    str.append(format!(
+        "Elections held: {}\n",
+        election_history.held_elections.len()
+    ));
+    for (i, held_election) in election_history.held_elections.iter().enumerate() {
+        str.append(format!(
+            "  {} {} ({}) - {} votes ({:.0}% turnout) - Winner: {} - {} - {} paradoxes\n",
+            held_election.name,
+            i + 1,
+            held_election.election.election_type.to_string(),
+            held_election.election.votes.len(),
+            held_election.turnout.percent(),
+            held_election.results[0].winners_to_string(),
+            held_election.construction_to_string(),
+            held_election.criteria.paradoxes.len()
+        ));
+    }
+
+    // How often each method agreed with the method the election was actually held under
+    let mut agreements = HashMap::<ElectionType, usize>::default();
+    for held_election in &election_history.held_elections {
+        let winner = held_election.results[0].get_winner();
+        for result in &held_election.results {
+            if result.get_winner() == winner {
+                *agreements.entry(result.get_type()).or_default() += 1;
+            }
+        }
+    }
+
+    str.append("Agreement with the held method:\n");
+    for election_type in ElectionType::iter() {
+        str.append(format!(
+            "  {}: {}/{}\n",
+            election_type.to_string(),
+            agreements.get(&election_type).copied().unwrap_or(0),
+            election_history.held_elections.len()
+        ));
+    }
+
+    // How often each method picked the same winners it would have without strategic voting
+    let mut held_up = HashMap::<ElectionType, usize>::default();
+    for held_election in &election_history.held_elections {
+        for (result, sincere_result) in held_election
+            .results
+            .iter()
+            .zip(&held_election.sincere_results)
+        {
+            if result.get_winners() == sincere_result.get_winners() {
+                *held_up.entry(result.get_type()).or_default() += 1;
+            }
+        }
+    }
+
+    str.append("Unchanged by strategic voting:\n");
+    for election_type in ElectionType::iter() {
+        str.append(format!(
+            "  {}: {}/{}\n",
+            election_type.to_string(),
+            held_up.get(&election_type).copied().unwrap_or(0),
+            election_history.held_elections.len()
+        ));
+    }
+
+    // Built here rather than read from the resource, which may not have caught up with the last election
+    let mut leaderboard = Leaderboard::default();
+    for held_election in &election_history.held_elections {
+        leaderboard.add(&held_election.satisfaction);
+    }
+
+    str.append("Voter satisfaction efficiency:\n");
+    for entry in leaderboard.ranked() {
+        str.append(format!(
+            "  {}: {:.2} (sincere {:.2}, regret {:.3})\n",
+            entry.election_type.to_string(),
+            entry.mean_vse(),
+            entry.mean_sincere_vse(),
+            entry.mean_bayesian_regret()
+        ));
+    }
+
+    str.string().unwrap()
+}
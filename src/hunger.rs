@@ -1,15 +1,18 @@
+use bevy::prelude::*;
 use bevy::reflect::TypeUuid;
-use bevy::{prelude::*, time::Time};
+use bevy::utils::HashMap;
 use serde::{Deserialize, Serialize};
 use std::hash::Hash;
+use std::time::Duration;
 use std::{collections::HashSet, ops::Sub};
+use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
 use crate::money::Money;
 use crate::sim_time::SimTime;
 use crate::world_stats;
 
-#[derive(Debug, Component)]
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct Stomach {
     pub max_size_ml: f64,
     pub filled_ml: f64,
@@ -69,23 +72,121 @@ impl FoodGroup {
             FoodGroup::Sugar => 0.5,
         }
     }
+
+    // The least and most of a balanced diet that should come from this group
+    pub fn healthy_share(&self) -> (f64, f64) {
+        match self {
+            FoodGroup::Vegetable => (0.1, 1.0),
+            FoodGroup::Fat => (0.0, 0.4),
+            FoodGroup::Sugar => (0.0, 0.3),
+            FoodGroup::Fruit | FoodGroup::Grain | FoodGroup::Meat | FoodGroup::Dairy => (0.0, 1.0),
+        }
+    }
+}
+
+// After this long half of what someone ate no longer counts towards their diet
+pub const DIET_HALF_LIFE: Duration = Duration::from_secs(60);
+// Below this many servings someone is starving rather than eating badly
+const MIN_JUDGED_INTAKE: f64 = 2.0;
+
+/// What someone has eaten lately, by food group.
+#[derive(Debug, Clone, Component, Default, Serialize, Deserialize)]
+pub struct Nutrition {
+    // Servings from each group, a serving is split evenly between its groups
+    intake: HashMap<FoodGroup, f64>,
+    pub malnourished_for: Duration,
+}
+
+impl Nutrition {
+    pub fn eat(&mut self, food: &Food, servings: f64) {
+        if food.groups.is_empty() {
+            return;
+        }
+
+        let per_group = servings / food.groups.len() as f64;
+        for group in &food.groups {
+            *self.intake.entry(*group).or_insert(0.0) += per_group;
+        }
+    }
+
+    /// Adds someone else's diet to this one, for the diet of a whole town.
+    pub fn add(&mut self, other: &Nutrition) {
+        for group in FoodGroup::iter() {
+            *self.intake.entry(group).or_insert(0.0) += other.intake(group);
+        }
+    }
+
+    pub fn intake(&self, group: FoodGroup) -> f64 {
+        self.intake.get(&group).copied().unwrap_or(0.0)
+    }
+
+    pub fn total(&self) -> f64 {
+        FoodGroup::iter().map(|group| self.intake(group)).sum()
+    }
+
+    pub fn share(&self, group: FoodGroup) -> f64 {
+        let total = self.total();
+        if total <= 0.0 {
+            return 0.0;
+        }
+
+        self.intake(group) / total
+    }
+
+    /// Groups there hasn't been enough of lately.
+    pub fn lacking(&self) -> Vec<FoodGroup> {
+        if self.total() < MIN_JUDGED_INTAKE {
+            return vec![];
+        }
+
+        FoodGroup::iter()
+            .filter(|group| self.share(*group) < group.healthy_share().0)
+            .collect()
+    }
+
+    /// Groups there has been too much of lately.
+    pub fn excess(&self) -> Vec<FoodGroup> {
+        if self.total() < MIN_JUDGED_INTAKE {
+            return vec![];
+        }
+
+        FoodGroup::iter()
+            .filter(|group| self.share(*group) > group.healthy_share().1)
+            .collect()
+    }
+
+    pub fn is_balanced(&self) -> bool {
+        self.lacking().is_empty() && self.excess().is_empty()
+    }
 }
 
-#[derive(Debug, Component, Clone, Default, PartialEq)]
+#[derive(Debug, Component, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Food {
     pub kcal: f64,
     pub ml: f64,
     pub groups: Vec<FoodGroup>,
+    // How long a serving keeps once made
+    #[serde(default = "default_food_shelf_life")]
+    pub shelf_life: Duration,
 }
 
 impl Food {
-    pub fn how_long_to_eat(&self) -> std::time::Duration {
+    pub fn how_long_to_eat(&self) -> Duration {
         let seconds = self.ml / 500.0;
-        std::time::Duration::from_secs_f64(seconds)
+        Duration::from_secs_f64(seconds)
+    }
+
+    /// What it sells for when supply and demand are even, dearer the more its farm costs to run.
+    pub fn base_price(&self) -> Money {
+        self.ml * upkeep_multiplier(&self.groups) / 100.0
     }
 }
 
-#[derive(Debug, Component)]
+fn upkeep_multiplier(groups: &[FoodGroup]) -> f64 {
+    groups.iter().map(|i| i.upkeep_multiplier()).sum::<f64>() / groups.len() as f64
+}
+
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct FoodPreferences {
     pub wont_eat: HashSet<FoodGroup>,
     pub prefers: HashSet<FoodGroup>,
@@ -104,15 +205,11 @@ impl FoodPreferences {
     }
 }
 
-pub fn drain_stomach_system(
-    time: Res<Time>,
-    sim_time: Res<SimTime>,
-    mut query: Query<&mut Stomach>,
-) {
+pub fn drain_stomach_system(sim_time: Res<SimTime>, mut query: Query<&mut Stomach>) {
     for mut stomach in &mut query {
         let new_value = stomach.filled_ml.sub(
             measurements::Volume::from_milliliters(50.0).as_milliliters()
-                * sim_time.delta_seconds(&time) as f64,
+                * sim_time.delta_seconds() as f64,
         );
         if new_value > 0.0 {
             stomach.filled_ml = new_value;
@@ -125,13 +222,41 @@ pub fn drain_stomach_system(
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub fn digest_system(sim_time: Res<SimTime>, mut query: Query<&mut Nutrition>) {
+    let kept = 0.5_f64.powf(sim_time.delta_seconds_f64() / DIET_HALF_LIFE.as_secs_f64());
+
+    for mut nutrition in &mut query {
+        for intake in nutrition.intake.values_mut() {
+            *intake *= kept;
+        }
+
+        if nutrition.is_balanced() {
+            nutrition.malnourished_for = Duration::ZERO;
+        } else {
+            nutrition.malnourished_for += sim_time.delta();
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct FoodTemplate {
     pub name: String,
     pub kcal: f64,
     pub ml: f64,
     pub groups: Vec<FoodGroup>,
     pub difficulty: u32,
+    // Seconds a serving keeps before it spoils
+    #[serde(default = "default_shelf_life")]
+    pub shelf_life: f32,
+}
+
+// For foods from before shelf life was tracked
+fn default_shelf_life() -> f32 {
+    60.0
+}
+
+fn default_food_shelf_life() -> Duration {
+    Duration::from_secs_f32(default_shelf_life())
 }
 
 impl Eq for FoodTemplate {}
@@ -148,19 +273,12 @@ impl FoodTemplate {
             kcal: self.kcal * 5.0,
             ml: self.ml * 5.0,
             groups: self.groups.clone(),
+            shelf_life: Duration::from_secs_f32(self.shelf_life),
         }
     }
 
     pub fn upkeep_cost(&self) -> Money {
-        let mut multiplier = self
-            .groups
-            .iter()
-            .map(|i| i.upkeep_multiplier())
-            .sum::<f64>();
-
-        multiplier /= self.groups.len() as f64;
-
-        self.ml * multiplier
+        self.ml * upkeep_multiplier(&self.groups)
     }
 }
 
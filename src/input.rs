@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 
-use crate::{sim_time::SimTime, ui::camera::GameCamera};
+use crate::{
+    save::{SaveWorldEvent, QUICK_SAVE_PATH},
+    sim_time::SimTime,
+    ui::camera::GameCamera,
+};
 
 pub fn player_input_camera_system(
     keyboard_input: Res<Input<KeyCode>>,
@@ -55,3 +59,14 @@ pub fn player_input_sim_time_system(
         sim_time.add_to_multiplier(SIM_TIME_MULTIPLIER_STEP);
     }
 }
+
+pub fn player_input_save_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut save_events: EventWriter<SaveWorldEvent>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F5) {
+        save_events.send(SaveWorldEvent {
+            path: QUICK_SAVE_PATH.into(),
+        });
+    }
+}
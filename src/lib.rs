@@ -0,0 +1,6 @@
+#![feature(const_fn_floating_point_arithmetic)]
+#![feature(generic_const_exprs)]
+
+//! The voting methods the sim holds its elections under, usable on any candidates.
+
+pub mod tally;
@@ -3,17 +3,19 @@
 extern crate measurements;
 use bevy::{
     app::App,
+    asset::LoadState,
     diagnostic::FrameTimeDiagnosticsPlugin,
+    ecs::schedule::ExecutorKind,
     prelude::*,
     time::{Timer, TimerMode},
     window::{PresentMode, Window, WindowPlugin},
     DefaultPlugins,
 };
 use bevy_common_assets::toml::TomlAssetPlugin;
-use bevy_enum_filter::prelude::*;
 use buildings::building::BuildingPlots;
 use rand::Rng;
 use sets::{AppState, LifeSet};
+use sim_time::SimSchedule;
 use world_stats::WorldStats;
 
 mod age;
@@ -26,150 +28,296 @@ mod elections;
 mod energy;
 mod goals;
 mod grave;
+mod headless;
 mod hunger;
 mod info;
 mod input;
 mod money;
 mod movement;
 mod name;
+mod navigation;
 mod people;
 mod player;
 mod reproduction;
 mod rng;
+mod save;
+mod scenario;
 mod sets;
 mod shelter;
 mod sim_setup;
 mod sim_time;
 mod stats;
+mod tax;
 mod text;
 mod ui;
 mod upkeep;
 mod world_stats;
 
-fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    scenario_file: Res<scenario::ScenarioFile>,
+) {
     let food_collection = FoodCollectionHandle(asset_server.load(assets::FOOD_CONFIG_FILE));
     commands.insert_resource(food_collection);
+
+    let scenario = ScenarioHandle {
+        path: scenario_file.path.clone(),
+        handle: asset_server.load(scenario_file.path.as_str()),
+    };
+    commands.insert_resource(scenario);
 }
 
 fn loading_world_assets(
     mut commands: Commands,
     mut state: ResMut<NextState<AppState>>,
+    asset_server: Res<AssetServer>,
     food_collection_handle: Res<FoodCollectionHandle>,
+    scenario_handle: Res<ScenarioHandle>,
     mut food_collection_asset: ResMut<Assets<hunger::FoodCollection>>,
+    mut scenario_asset: ResMut<Assets<scenario::Scenario>>,
 ) {
-    if let Some(food_collection) = food_collection_asset.remove(food_collection_handle.0.id()) {
-        commands.insert_resource(food_collection);
-        state.set(AppState::SettingUpWorld);
+    if asset_server.get_load_state(&scenario_handle.handle) == LoadState::Failed {
+        panic!("Couldn't load scenario {}", scenario_handle.path);
+    }
+
+    if !food_collection_asset.contains(&food_collection_handle.0)
+        || !scenario_asset.contains(&scenario_handle.handle)
+    {
+        return;
     }
+
+    let food_collection = food_collection_asset
+        .remove(food_collection_handle.0.id())
+        .unwrap();
+    commands.insert_resource(food_collection);
+
+    let mut scenario = scenario_asset.remove(scenario_handle.handle.id()).unwrap();
+    assert!(
+        !scenario.elections.types.is_empty(),
+        "Scenario {} doesn't allow any election types",
+        scenario_handle.path
+    );
+
+    // Written back so a saved world remembers which seed it came from
+    let seed = *scenario
+        .seed
+        .get_or_insert_with(|| rand::thread_rng().gen());
+    info!("Setting up {} with seed {}", scenario_handle.path, seed);
+    commands.insert_resource(rng::Rng::new(seed));
+    commands.insert_resource(scenario);
+
+    state.set(AppState::SettingUpWorld);
 }
 
 fn main() {
-    let seed: u64 = if cfg!(wasm32) {
-        rand::thread_rng().gen()
-    } else {
-        101
-    };
+    let mut app = App::new();
 
-    App::new()
-        .add_enum_filter::<buildings::building::BuildingStatus>()
-        .add_enum_filter::<goals::Goals>()
-        .add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(Window {
-                title: "VotingVotingVoting".into(),
-                resolution: (800., 600.).into(),
-                present_mode: PresentMode::AutoVsync,
+    app.edit_schedule(SimSchedule, |schedule| {
+        // Ambiguous systems are ordered by hashing that differs between runs,
+        // so every system in a set is chained to run in the same order each time
+        schedule
+            .set_executor_kind(ExecutorKind::SingleThreaded)
+            .add_systems(
+                (
+                    sim_time::update_enum_filter_system::<buildings::building::BuildingStatus>,
+                    sim_time::update_enum_filter_system::<goals::Goals>,
+                    apply_system_buffers,
+                )
+                    .chain()
+                    .before(PhysicsSet::Movement)
+                    .before(LifeSet::World),
+            )
+            .configure_set(PhysicsSet::Movement.before(PhysicsSet::CollisionDetection))
+            .configure_set(PhysicsSet::CollisionDetection.before(LifeSet::World))
+            .configure_set(LifeSet::World.before(LifeSet::Decide))
+            .configure_set(LifeSet::Decide.before(LifeSet::Goal))
+            .configure_set(LifeSet::Goal.before(LifeSet::Mortal))
+            .configure_set(LifeSet::Mortal.before(LifeSet::MortalResponse));
+    });
+
+    match headless::HeadlessConfig::from_args(std::env::args()) {
+        Some(config) => {
+            app.add_plugins(MinimalPlugins)
+                .add_plugin(AssetPlugin::default())
+                .add_plugin(headless::HeadlessPlugin { config });
+        }
+        None => {
+            app.add_plugins(DefaultPlugins.set(WindowPlugin {
+                primary_window: Some(Window {
+                    title: "VotingVotingVoting".into(),
+                    resolution: (800., 600.).into(),
+                    present_mode: PresentMode::AutoVsync,
+                    ..default()
+                }),
                 ..default()
-            }),
-            ..default()
-        }))
-        .add_plugin(TomlAssetPlugin::<hunger::FoodCollection>::new(&[
-            "food_collection.toml",
-        ]))
-        .insert_resource(death::CheckOldAgeTimer(Timer::from_seconds(
-            0.5,
-            TimerMode::Repeating,
-        )))
-        .insert_resource(collision::CollisionTimer(Timer::from_seconds(
-            0.05,
-            TimerMode::Repeating,
-        )))
-        .insert_resource(elections::election::ElectionTimer(Timer::from_seconds(
-            20.0,
-            TimerMode::Repeating,
-        )))
-        .insert_resource(name::NameGenerator::default())
-        .insert_resource(elections::election::ElectionHistory::default())
-        .insert_resource(BuildingPlots::new())
-        .insert_resource(money::Treasury::new())
-        .insert_resource(WorldStats::new())
-        .add_plugin(rng::RngPlugin::with_seed(rng::Seed::Number(seed)))
-        .add_plugin(FrameTimeDiagnosticsPlugin::default())
-        .add_plugin(stats::StatsPlugin)
-        .add_state::<AppState>()
-        .add_event::<elections::election::ElectionClosedEvent>()
-        .add_plugin(ui::VotiesUiPlugin)
-        .add_plugin(goals::GoalsPlugin)
-        .add_startup_system(setup)
-        .add_system(loading_world_assets.run_if(in_state(AppState::Loading)))
-        .add_system(sim_setup::setting_up_world.run_if(in_state(AppState::SettingUpWorld)))
-        .add_system(ui::setup.run_if(in_state(AppState::SettingUpUi)))
-        .add_systems(
-            (movement::go_to_target, movement::apply_velcoity_system)
-                .chain()
-                .in_set(PhysicsSet::Movement),
+            }))
+            .add_plugin(FrameTimeDiagnosticsPlugin::default())
+            .add_plugin(stats::StatsPlugin)
+            .add_plugin(ui::VotiesUiPlugin)
+            .add_system(ui::setup.run_if(in_state(AppState::SettingUpUi)))
+            .add_systems((
+                input::player_input_camera_system.run_if(in_state(AppState::Running)),
+                input::player_input_sim_time_system.run_if(in_state(AppState::Running)),
+                input::player_input_save_system.run_if(in_state(AppState::Running)),
+            ));
+        }
+    }
+
+    if let Some(load) = save::LoadWorld::from_args(std::env::args()) {
+        app.insert_resource(load);
+    }
+    if let Some(export) = elections::export::ElectionExport::from_args(std::env::args()) {
+        app.insert_resource(export);
+    }
+    if let Some(recorder) = world_stats::WorldStatsRecorder::from_args(std::env::args()) {
+        app.insert_resource(recorder);
+    }
+
+    app.add_plugin(TomlAssetPlugin::<hunger::FoodCollection>::new(&[
+        "food_collection.toml",
+    ]))
+    .add_plugin(TomlAssetPlugin::<scenario::Scenario>::new(&[
+        "scenario.toml",
+    ]))
+    .insert_resource(scenario::ScenarioFile::from_args(std::env::args()))
+    .insert_resource(death::CheckOldAgeTimer(Timer::from_seconds(
+        0.5,
+        TimerMode::Repeating,
+    )))
+    .insert_resource(collision::CollisionTimer(Timer::from_seconds(
+        0.05,
+        TimerMode::Repeating,
+    )))
+    .insert_resource(collision::SpatialHash::default())
+    .insert_resource(buildings::index::BuildingIndex::default())
+    .insert_resource(navigation::NavGrid::default())
+    .insert_resource(name::NameGenerator::default())
+    .insert_resource(elections::election::ElectionHistory::default())
+    .insert_resource(elections::polling::OpinionPolls::default())
+    .insert_resource(elections::satisfaction::Leaderboard::default())
+    .insert_resource(BuildingPlots::new())
+    .insert_resource(money::Treasury::new())
+    .insert_resource(tax::TaxPolicy::default())
+    .insert_resource(tax::TaxCollection::default())
+    .insert_resource(WorldStats::new())
+    .add_plugin(rng::RngPlugin)
+    .add_state::<AppState>()
+    .add_event::<elections::election::ElectionClosedEvent>()
+    .add_event::<save::SaveWorldEvent>()
+    .add_plugin(goals::GoalsPlugin)
+    .add_startup_system(setup)
+    .add_system(loading_world_assets.run_if(in_state(AppState::Loading)))
+    .add_system(
+        sim_setup::setting_up_world
+            .run_if(in_state(AppState::SettingUpWorld))
+            .run_if(not(resource_exists::<save::LoadWorld>())),
+    )
+    .add_system(
+        save::load_world_system
+            .run_if(in_state(AppState::SettingUpWorld))
+            .run_if(resource_exists::<save::LoadWorld>()),
+    )
+    .add_system(sim_time::run_sim_schedule_system.run_if(in_state(AppState::Running)))
+    .add_system(
+        elections::satisfaction::update_leaderboard_system
+            .after(sim_time::run_sim_schedule_system)
+            .run_if(in_state(AppState::Running)),
+    )
+    .add_system(
+        elections::export::export_held_elections_system
+            .after(elections::satisfaction::update_leaderboard_system)
+            .run_if(in_state(AppState::Running))
+            .run_if(resource_exists::<elections::export::ElectionExport>()),
+    )
+    .add_system(
+        save::save_world_system
+            .in_base_set(CoreSet::Last)
+            .run_if(in_state(AppState::Running)),
+    )
+    .add_systems(
+        (
+            navigation::update_nav_grid_system,
+            navigation::plan_paths_system,
+            movement::go_to_target,
+            movement::apply_velcoity_system,
         )
-        .add_system(collision::collision_detection_system.in_set(PhysicsSet::CollisionDetection))
-        .configure_set(PhysicsSet::Movement.before(PhysicsSet::CollisionDetection))
-        .configure_set(PhysicsSet::Movement.run_if(in_state(AppState::Running)))
-        .configure_set(PhysicsSet::CollisionDetection.run_if(in_state(AppState::Running)))
-        .add_systems((
+            .chain()
+            .in_set(PhysicsSet::Movement)
+            .in_schedule(SimSchedule),
+    )
+    .add_system(
+        collision::collision_detection_system
+            .in_set(PhysicsSet::CollisionDetection)
+            .in_schedule(SimSchedule),
+    )
+    .add_systems(
+        (
+            buildings::workplace::count_workers_system.in_set(LifeSet::World),
             hunger::drain_stomach_system.in_set(LifeSet::World),
+            hunger::digest_system.in_set(LifeSet::World),
             energy::drain_energy_system.in_set(LifeSet::World),
-            brain::decide_system.in_set(LifeSet::Decide),
             people::update_info_text.in_set(LifeSet::World),
             age::age_up_system.in_set(LifeSet::World),
             buildings::farm::farms_make_food_system.in_set(LifeSet::World),
+            buildings::farm::spoil_food_system.in_set(LifeSet::World),
             people::give_birth_system.in_set(LifeSet::World),
-            death::death_from_exhaustion_system.in_set(LifeSet::Mortal),
-            death::die_of_old_age_system.in_set(LifeSet::Mortal),
+            buildings::index::update_building_index_system.in_set(LifeSet::World),
             elections::election::start_election_system.in_set(LifeSet::World),
+            elections::polling::take_opinion_polls_system.in_set(LifeSet::World),
             elections::election::close_elections_system.in_set(LifeSet::World),
-        ))
-        .add_systems((
+            buildings::construction::construction_queue_system.in_set(LifeSet::World),
+        )
+            .chain()
+            .in_schedule(SimSchedule),
+    )
+    .add_systems(
+        (
             buildings::building::update_building_tint_system.in_set(LifeSet::World),
             buildings::mint::mints_have_become_dilapidated_system.in_set(LifeSet::World),
             buildings::mint::mint_produce_system.in_set(LifeSet::World),
             upkeep::upkeep_cost_system.in_set(LifeSet::World),
+            tax::collect_taxes_system.in_set(LifeSet::World),
             buildings::building::change_building_status_system.in_set(LifeSet::World),
             buildings::money_hole::update_treasury_capacity_system.in_set(LifeSet::World),
             world_stats::world_stats_update_system.in_set(LifeSet::World),
+            world_stats::record_world_stats_system
+                .run_if(resource_exists::<world_stats::WorldStatsRecorder>())
+                .in_set(LifeSet::World),
             buildings::house::update_house_text_system.in_set(LifeSet::World),
             shelter::tick_homeless_system.in_set(LifeSet::World),
-            death::die_of_homelessness_system.in_set(LifeSet::Mortal),
             buildings::house::clear_dead_from_house_system.in_set(LifeSet::World),
             buildings::house::empty_dilapidated_house_system.in_set(LifeSet::World),
             reproduction::reproductive_timer_tick_system.in_set(LifeSet::World),
             sim_time::tick_sim_time_system.in_set(LifeSet::World),
-        ))
-        .add_systems((
-            input::player_input_camera_system.run_if(in_state(AppState::Running)),
-            input::player_input_sim_time_system.run_if(in_state(AppState::Running)),
-        ))
-        .add_systems(
-            (grave::create_grave_system, death::remove_dead_system)
-                .chain()
-                .in_set(LifeSet::MortalResponse),
         )
-        .configure_set(LifeSet::World.before(LifeSet::Decide))
-        .configure_set(LifeSet::Decide.before(LifeSet::Goal))
-        .configure_set(LifeSet::Goal.before(LifeSet::Mortal))
-        .configure_set(LifeSet::Mortal.before(LifeSet::MortalResponse))
-        .configure_set(LifeSet::World.run_if(in_state(AppState::Running)))
-        .configure_set(LifeSet::Decide.run_if(in_state(AppState::Running)))
-        .configure_set(LifeSet::Goal.run_if(in_state(AppState::Running)))
-        .configure_set(LifeSet::Mortal.run_if(in_state(AppState::Running)))
-        .configure_set(LifeSet::MortalResponse.run_if(in_state(AppState::Running)))
-        .run();
+            .chain()
+            .after(buildings::construction::construction_queue_system)
+            .in_schedule(SimSchedule),
+    )
+    .add_system(
+        brain::decide_system
+            .in_set(LifeSet::Decide)
+            .in_schedule(SimSchedule),
+    )
+    .add_systems(
+        (
+            death::death_from_exhaustion_system,
+            death::die_of_old_age_system,
+            death::die_of_homelessness_system,
+            death::die_of_malnutrition_system,
+        )
+            .chain()
+            .in_set(LifeSet::Mortal)
+            .in_schedule(SimSchedule),
+    )
+    .add_systems(
+        (grave::create_grave_system, death::remove_dead_system)
+            .chain()
+            .in_set(LifeSet::MortalResponse)
+            .in_schedule(SimSchedule),
+    )
+    .run();
 }
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
@@ -180,3 +328,9 @@ enum PhysicsSet {
 
 #[derive(Resource)]
 struct FoodCollectionHandle(Handle<hunger::FoodCollection>);
+
+#[derive(Resource)]
+struct ScenarioHandle {
+    path: String,
+    handle: Handle<scenario::Scenario>,
+}
@@ -1,8 +1,9 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 pub type Money = f64;
 
-#[derive(Debug, Resource)]
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
 pub struct Treasury {
     pub money: Money,
     pub capacity: Money,
@@ -45,3 +46,19 @@ impl Treasury {
         (self.money / self.capacity) as f32
     }
 }
+
+/// What someone has earned and not yet spent.
+#[derive(Debug, Clone, Default, Component, Serialize, Deserialize)]
+pub struct Wallet {
+    pub money: Money,
+    // Wages paid since taxes were last collected
+    #[serde(default)]
+    pub earned: Money,
+}
+
+impl Wallet {
+    pub fn earn(&mut self, amount: Money) {
+        self.money += amount;
+        self.earned += amount;
+    }
+}
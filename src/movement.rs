@@ -1,30 +1,67 @@
+use std::collections::VecDeque;
+
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::sim_time::SimTime;
 
-#[derive(Component, Default)]
+#[derive(Component, Clone, Default, Serialize, Deserialize)]
 pub struct Velocity {
     x: f32,
     y: f32,
 }
 
-#[derive(Debug, Component)]
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct MovementSpeed(pub f32);
 
-#[derive(Debug, Component)]
+// Close enough to a waypoint to head for the next one
+const WAYPOINT_RADIUS: f32 = 5.0;
+
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct MovementGoal {
     pub target: Option<Vec3>,
+    // Walked through in order before heading straight for the target
+    #[serde(default)]
+    pub waypoints: VecDeque<Vec3>,
+    // The target the waypoints lead to, once it differs from target the path is replanned
+    #[serde(default)]
+    pub planned_for: Option<Vec3>,
 }
 
 impl Default for MovementGoal {
     fn default() -> Self {
-        Self { target: None }
+        Self {
+            target: None,
+            waypoints: VecDeque::new(),
+            planned_for: None,
+        }
     }
 }
 
-pub fn go_to_target(mut query: Query<(&MovementGoal, &MovementSpeed, &Transform, &mut Velocity)>) {
-    for (movement_goal, speed, transform, mut vel) in &mut query {
-        if let Some(target) = movement_goal.target {
+pub fn go_to_target(
+    sim_time: Res<SimTime>,
+    mut query: Query<(&mut MovementGoal, &MovementSpeed, &Transform, &mut Velocity)>,
+) {
+    for (mut movement_goal, speed, transform, mut vel) in &mut query {
+        let step = speed.0 * sim_time.delta_seconds();
+        while let Some(waypoint) = movement_goal.waypoints.front() {
+            if transform
+                .translation
+                .truncate()
+                .distance(waypoint.truncate())
+                > step.max(WAYPOINT_RADIUS)
+            {
+                break;
+            }
+            movement_goal.waypoints.pop_front();
+        }
+
+        let next = movement_goal
+            .waypoints
+            .front()
+            .copied()
+            .or(movement_goal.target);
+        if let Some(target) = next {
             let direction = (target - transform.translation).normalize();
             let velocity = direction * speed.0;
             vel.x = velocity.x;
@@ -34,12 +71,11 @@ pub fn go_to_target(mut query: Query<(&MovementGoal, &MovementSpeed, &Transform,
 }
 
 pub fn apply_velcoity_system(
-    time: Res<Time>,
     sim_time: Res<SimTime>,
     mut query: Query<(&mut Transform, &Velocity)>,
 ) {
     for (mut transform, vel) in &mut query {
-        transform.translation.x += (vel.x * sim_time.delta_seconds(&time)).min(50.0);
-        transform.translation.y += (vel.y * sim_time.delta_seconds(&time)).min(50.0);
+        transform.translation.x += (vel.x * sim_time.delta_seconds()).min(50.0);
+        transform.translation.y += (vel.y * sim_time.delta_seconds()).min(50.0);
     }
 }
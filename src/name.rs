@@ -1,9 +1,10 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Component, Default)]
+#[derive(Component, Clone, Default, Serialize, Deserialize)]
 pub struct Name(pub String);
 
-#[derive(Resource)]
+#[derive(Clone, Resource, Serialize, Deserialize)]
 pub struct NameGenerator {
     top_number: u64,
 }
@@ -0,0 +1,270 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    buildings::building::{Building, PLOT_SIZE_X, PLOT_SIZE_Y},
+    movement::{MovementGoal, MovementSpeed},
+};
+
+// A sixth of a plot so the street between two buildings is two cells wide
+pub const CELL_SIZE: Vec2 = Vec2::new(PLOT_SIZE_X / 6.0, PLOT_SIZE_Y / 6.0);
+// Every building sprite is 100 by 100
+const BUILDING_HALF_SIZE: Vec2 = Vec2::new(50.0, 50.0);
+// Past this the target is treated as unreachable and walked to in a straight line
+const MAX_SEARCHED_CELLS: usize = 20_000;
+const STRAIGHT_COST: i32 = 10;
+const DIAGONAL_COST: i32 = 14;
+
+const NEIGHBOURS: [IVec2; 8] = [
+    IVec2::new(1, 0),
+    IVec2::new(-1, 0),
+    IVec2::new(0, 1),
+    IVec2::new(0, -1),
+    IVec2::new(1, 1),
+    IVec2::new(1, -1),
+    IVec2::new(-1, 1),
+    IVec2::new(-1, -1),
+];
+
+/// Which cells of the world are taken up by a building. Everywhere else is open ground.
+#[derive(Debug, Default, Resource)]
+pub struct NavGrid {
+    blocked: HashMap<IVec2, Entity>,
+    buildings: HashMap<Entity, Vec<IVec2>>,
+}
+
+impl NavGrid {
+    pub fn cell(position: Vec3) -> IVec2 {
+        (position.truncate() / CELL_SIZE).floor().as_ivec2()
+    }
+
+    pub fn cell_center(cell: IVec2) -> Vec2 {
+        (cell.as_vec2() + Vec2::splat(0.5)) * CELL_SIZE
+    }
+
+    pub fn insert_building(&mut self, entity: Entity, translation: Vec3) {
+        let min = Self::cell((translation.truncate() - BUILDING_HALF_SIZE).extend(0.0));
+        // Take off a hair so a building ending on a cell edge doesn't claim the next cell
+        let max = Self::cell(
+            (translation.truncate() + BUILDING_HALF_SIZE - Vec2::splat(0.01)).extend(0.0),
+        );
+
+        let mut cells = Vec::new();
+        for x in min.x..=max.x {
+            for y in min.y..=max.y {
+                let cell = IVec2::new(x, y);
+                self.blocked.insert(cell, entity);
+                cells.push(cell);
+            }
+        }
+        self.buildings.insert(entity, cells);
+    }
+
+    pub fn remove_building(&mut self, entity: Entity) {
+        if let Some(cells) = self.buildings.remove(&entity) {
+            for cell in cells {
+                if self.blocked.get(&cell) == Some(&entity) {
+                    self.blocked.remove(&cell);
+                }
+            }
+        }
+    }
+
+    pub fn building_at(&self, cell: IVec2) -> Option<Entity> {
+        self.blocked.get(&cell).copied()
+    }
+
+    fn walkable(&self, cell: IVec2, allowed: &[Option<Entity>; 2]) -> bool {
+        match self.blocked.get(&cell) {
+            Some(building) => allowed.contains(&Some(*building)),
+            None => true,
+        }
+    }
+
+    // Samples along the segment so a straight walk doesn't clip a building
+    fn line_of_sight(&self, from: Vec2, to: Vec2, allowed: &[Option<Entity>; 2]) -> bool {
+        let step = CELL_SIZE.min_element() / 4.0;
+        let steps = (from.distance(to) / step).ceil() as i32;
+        (0..=steps).all(|i| {
+            let point = from.lerp(to, i as f32 / steps.max(1) as f32);
+            self.walkable(Self::cell(point.extend(0.0)), allowed)
+        })
+    }
+
+    fn heuristic(from: IVec2, to: IVec2) -> i32 {
+        let delta = (to - from).abs();
+        let diagonal = delta.min_element();
+        let straight = delta.max_element() - diagonal;
+        diagonal * DIAGONAL_COST + straight * STRAIGHT_COST
+    }
+
+    /// Waypoints to walk through on the way from `from` to `to`, not including `to` itself.
+    /// The buildings `from` and `to` are in can be walked through so people can leave and enter them.
+    /// Returns `None` if there's no way around the buildings in between.
+    pub fn find_path(&self, from: Vec3, to: Vec3) -> Option<Vec<Vec3>> {
+        let start = Self::cell(from);
+        let goal = Self::cell(to);
+        let allowed = [self.building_at(start), self.building_at(goal)];
+
+        if self.line_of_sight(from.truncate(), to.truncate(), &allowed) {
+            return Some(Vec::new());
+        }
+
+        let mut open = BinaryHeap::new();
+        let mut came_from: HashMap<IVec2, IVec2> = HashMap::default();
+        let mut cost: HashMap<IVec2, i32> = HashMap::default();
+
+        cost.insert(start, 0);
+        // Ties are broken on the cell itself so the same path is found every run
+        open.push(Reverse((
+            Self::heuristic(start, goal),
+            0,
+            (start.x, start.y),
+        )));
+
+        let mut searched = 0;
+        while let Some(Reverse((_, current_cost, (x, y)))) = open.pop() {
+            let current = IVec2::new(x, y);
+            if current == goal {
+                break;
+            }
+            if current_cost > cost[&current] {
+                continue;
+            }
+
+            searched += 1;
+            if searched > MAX_SEARCHED_CELLS {
+                return None;
+            }
+
+            for offset in NEIGHBOURS {
+                let next = current + offset;
+                if !self.walkable(next, &allowed) {
+                    continue;
+                }
+                // No cutting across the corner of a building
+                let diagonal = offset.x != 0 && offset.y != 0;
+                if diagonal
+                    && (!self.walkable(current + IVec2::new(offset.x, 0), &allowed)
+                        || !self.walkable(current + IVec2::new(0, offset.y), &allowed))
+                {
+                    continue;
+                }
+
+                let next_cost = current_cost
+                    + if diagonal {
+                        DIAGONAL_COST
+                    } else {
+                        STRAIGHT_COST
+                    };
+                if !matches!(cost.get(&next), Some(existing) if *existing <= next_cost) {
+                    cost.insert(next, next_cost);
+                    came_from.insert(next, current);
+                    open.push(Reverse((
+                        next_cost + Self::heuristic(next, goal),
+                        next_cost,
+                        (next.x, next.y),
+                    )));
+                }
+            }
+        }
+
+        if !came_from.contains_key(&goal) {
+            return None;
+        }
+
+        let mut cells = vec![goal];
+        while let Some(previous) = came_from.get(cells.last().unwrap()) {
+            if *previous == start {
+                break;
+            }
+            cells.push(*previous);
+        }
+        cells.reverse();
+
+        let mut points = cells.into_iter().map(Self::cell_center).collect::<Vec<_>>();
+        // The goal cell is swapped for the target itself
+        points.pop();
+        points.push(to.truncate());
+
+        // Skip past every point that can be reached in a straight line
+        let mut waypoints = Vec::new();
+        let mut position = from.truncate();
+        let mut i = 0;
+        while i < points.len() - 1 {
+            let furthest = (i..points.len())
+                .rev()
+                .find(|j| self.line_of_sight(position, points[*j], &allowed))
+                .unwrap_or(i);
+            if furthest == points.len() - 1 {
+                break;
+            }
+
+            position = points[furthest];
+            waypoints.push(position.extend(to.z));
+            i = furthest + 1;
+        }
+
+        Some(waypoints)
+    }
+
+    /// How far someone would walk getting from `from` to `to` along their path.
+    pub fn travel_distance(&self, from: Vec3, to: Vec3) -> f32 {
+        let waypoints = self.find_path(from, to).unwrap_or_default();
+
+        let mut distance = 0.0;
+        let mut position = from.truncate();
+        for point in waypoints
+            .iter()
+            .map(|waypoint| waypoint.truncate())
+            .chain([to.truncate()])
+        {
+            distance += position.distance(point);
+            position = point;
+        }
+
+        distance
+    }
+}
+
+pub fn update_nav_grid_system(
+    mut nav_grid: ResMut<NavGrid>,
+    added: Query<(Entity, &Transform), Added<Building>>,
+    mut removed: RemovedComponents<Building>,
+) {
+    // Only touched when a building comes or goes so paths aren't replanned for nothing
+    let removed = removed.iter().collect::<Vec<_>>();
+    if added.is_empty() && removed.is_empty() {
+        return;
+    }
+
+    for entity in removed {
+        nav_grid.remove_building(entity);
+    }
+
+    for (entity, transform) in &added {
+        nav_grid.insert_building(entity, transform.translation);
+    }
+}
+
+pub fn plan_paths_system(
+    nav_grid: Res<NavGrid>,
+    mut query: Query<(&mut MovementGoal, &Transform), With<MovementSpeed>>,
+) {
+    for (mut movement_goal, transform) in &mut query {
+        // Replanned when a building goes up or down since the old path might cut through it
+        if movement_goal.planned_for == movement_goal.target && !nav_grid.is_changed() {
+            continue;
+        }
+
+        let waypoints = match movement_goal.target {
+            Some(target) => nav_grid
+                .find_path(transform.translation, target)
+                .unwrap_or_default(),
+            None => Vec::new(),
+        };
+        movement_goal.waypoints = waypoints.into();
+        movement_goal.planned_for = movement_goal.target;
+    }
+}
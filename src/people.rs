@@ -5,13 +5,16 @@ use crate::{
     elections::voter::Voter,
     energy, goals,
     hunger::{self, Stomach},
+    money::{Money, Wallet},
     movement::{self},
     name,
     reproduction::{self, get_reproduction_cooldown},
-    rng, shelter,
+    rng,
+    scenario::Scenario,
+    shelter,
     sim_time::SimTime,
 };
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
 use rand::{seq::IteratorRandom, Rng};
 use strum::IntoEnumIterator;
 
@@ -90,48 +93,103 @@ pub fn create_prefer_eat(
     result.into_iter().collect()
 }
 
+#[derive(Bundle)]
+pub struct PersonBundle {
+    #[bundle]
+    pub sprite: SpriteBundle,
+    pub stomach: hunger::Stomach,
+    pub energy: energy::Energy,
+    pub food_preferences: hunger::FoodPreferences,
+    pub movement_goal: movement::MovementGoal,
+    pub movement_speed: movement::MovementSpeed,
+    pub velocity: movement::Velocity,
+    pub person: Person,
+    pub collider: collision::Collider,
+    pub collision_holder: collision::CollisionHolder,
+    pub mortal: death::Mortal,
+    pub age: age::Age,
+    pub brain: brain::Brain,
+    pub name: name::Name,
+    pub reproductive: reproduction::Reproductive,
+    pub voter: Voter,
+    pub goal: goals::Goals,
+    pub requires_house: shelter::RequiresHouse,
+    pub wallet: Wallet,
+    pub nutrition: hunger::Nutrition,
+}
+
+pub fn person_sprite(asset_server: &AssetServer, location: Vec3) -> SpriteBundle {
+    SpriteBundle {
+        texture: asset_server.load(assets::DEFAULT_PERSON_SPRITE_PATH),
+        transform: Transform::from_translation(location),
+        ..default()
+    }
+}
+
+/// What is decided about someone before they are spawned, the rest is rolled by `create_person`.
+pub struct NewPerson<'a> {
+    pub spawn_location: Vec3,
+    pub stomach_size_range: (measurements::Volume, measurements::Volume),
+    pub speed: f32,
+    pub age: Duration,
+    pub wont_eat_groups: &'a [hunger::FoodGroup],
+    pub prefer_eat_groups: &'a [hunger::FoodGroup],
+    pub strategic_fraction: f32,
+    pub money: Money,
+}
+
+// Everything needed to bring someone into the world
+#[derive(SystemParam)]
+pub struct PersonSpawner<'w, 's> {
+    pub commands: Commands<'w, 's>,
+    pub asset_server: Res<'w, AssetServer>,
+    pub rng: ResMut<'w, rng::Rng>,
+    pub name_gen: ResMut<'w, name::NameGenerator>,
+}
+
 pub fn create_person(
     commands: &mut Commands,
-    time: &Time,
     asset_server: &AssetServer,
     rng: &mut impl rand::Rng,
     name: &str,
-    spawn_location: Vec3,
-    stomach_size_range: (measurements::Volume, measurements::Volume),
-    speed: f32,
-    age: Duration,
-    wont_eat_groups: &[hunger::FoodGroup],
-    prefer_eat_groups: &[hunger::FoodGroup],
+    person: NewPerson,
 ) {
+    let NewPerson {
+        spawn_location,
+        stomach_size_range,
+        speed,
+        age,
+        wont_eat_groups,
+        prefer_eat_groups,
+        strategic_fraction,
+        money,
+    } = person;
+
     let person_entity = commands
-        .spawn((
-            SpriteBundle {
-                texture: asset_server.load(assets::DEFAULT_PERSON_SPRITE_PATH),
-                transform: Transform::from_translation(spawn_location),
-                ..default()
-            },
-            create_person_stomach(rng, stomach_size_range),
-            energy::Energy::new(rng.gen_range(1000.0..2500.0), 2500.0),
-            hunger::FoodPreferences::new(wont_eat_groups, prefer_eat_groups),
-            movement::MovementGoal::default(),
-            movement::MovementSpeed(speed),
-            movement::Velocity::default(),
-            Person::default(),
-            collision::Collider,
-            collision::CollisionHolder::default(),
-            death::Mortal::default(),
-            age::Age {
+        .spawn(PersonBundle {
+            sprite: person_sprite(asset_server, spawn_location),
+            stomach: create_person_stomach(rng, stomach_size_range),
+            energy: energy::Energy::new(rng.gen_range(1000.0..2500.0), 2500.0),
+            food_preferences: hunger::FoodPreferences::new(wont_eat_groups, prefer_eat_groups),
+            movement_goal: movement::MovementGoal::default(),
+            movement_speed: movement::MovementSpeed(speed),
+            velocity: movement::Velocity::default(),
+            person: Person::default(),
+            collider: collision::Collider,
+            collision_holder: collision::CollisionHolder::default(),
+            mortal: death::Mortal::default(),
+            age: age::Age {
                 duration_alive: age,
             },
-            brain::Brain,
-            name::Name(name.to_string()),
-            reproduction::Reproductive::new(get_reproduction_cooldown(rng)),
-        ))
-        .insert((
-            Voter::new_random(rng),
-            goals::Goals::None,
-            shelter::RequiresHouse::default(),
-        ))
+            brain: brain::Brain,
+            name: name::Name(name.to_string()),
+            reproductive: reproduction::Reproductive::new(get_reproduction_cooldown(rng)),
+            voter: Voter::new_random(rng, strategic_fraction),
+            goal: goals::Goals::None,
+            requires_house: shelter::RequiresHouse::default(),
+            wallet: Wallet { money, earned: 0.0 },
+            nutrition: hunger::Nutrition::default(),
+        })
         .id();
 
     // let info_text = commands
@@ -216,12 +274,9 @@ pub fn update_info_text(
 }
 
 pub fn give_birth_system(
-    mut commands: Commands,
-    time: Res<Time>,
+    mut spawner: PersonSpawner,
     sim_time: Res<SimTime>,
-    asset_server: Res<AssetServer>,
-    mut rng: ResMut<rng::Rng>,
-    mut name_gen: ResMut<name::NameGenerator>,
+    scenario: Res<Scenario>,
     parents_q: Query<
         (
             &hunger::Stomach,
@@ -241,11 +296,7 @@ pub fn give_birth_system(
     >,
 ) {
     for (entity, mut pregnant, trans, mut energy) in &mut pregnant_q {
-        if pregnant
-            .pregnancy_timer
-            .tick(sim_time.delta(&time))
-            .finished()
-        {
+        if pregnant.pregnancy_timer.tick(sim_time.delta()).finished() {
             let min_stomach_range;
             let max_stomach_range;
             {
@@ -294,7 +345,7 @@ pub fn give_birth_system(
                     .unwrap()
                     * 1.1;
 
-                speed = rng.inner.gen_range(min_speed..max_speed);
+                speed = spawner.rng.inner.gen_range(min_speed..max_speed);
             }
 
             let wont_eat_groups: HashSet<_>;
@@ -316,47 +367,55 @@ pub fn give_birth_system(
                 let food_groups_count = food_groups.len();
 
                 let wont_eat_count = if complete_set.len() == 0 {
-                    rng.inner
+                    spawner
+                        .rng
+                        .inner
                         .gen_range(0..(complete_set.len() + 1).min(food_groups_count))
                 } else {
-                    wont_eat_count(&mut rng.inner)
+                    wont_eat_count(&mut spawner.rng.inner)
                 };
 
                 let mut wont_eat = HashSet::new();
                 while wont_eat.len() < wont_eat_count {
                     let food_group = hunger::FoodGroup::iter()
                         .filter(|fg| !wont_eat.contains(fg))
-                        .choose(&mut rng.inner)
+                        .choose(&mut spawner.rng.inner)
                         .unwrap();
                     wont_eat.insert(food_group);
                 }
 
-                fill_wont_eat(wont_eat_count, &mut wont_eat, &mut rng.inner);
+                fill_wont_eat(wont_eat_count, &mut wont_eat, &mut spawner.rng.inner);
                 wont_eat_groups = wont_eat;
             }
-            let prefer_eat = create_prefer_eat(&mut rng.inner, &wont_eat_groups, None);
+            let prefer_eat = create_prefer_eat(&mut spawner.rng.inner, &wont_eat_groups, None);
             let wont_eat_groups: Vec<_> = wont_eat_groups.into_iter().collect();
 
             energy.use_kcal(1000.0);
 
             create_person(
-                &mut commands,
-                &time,
-                &asset_server,
-                &mut rng.inner,
-                name_gen.generate().as_str(),
-                trans.translation,
-                (
-                    measurements::Volume::from_milliliters(min_stomach_range),
-                    measurements::Volume::from_milliliters(max_stomach_range),
-                ),
-                speed,
-                Duration::ZERO,
-                &wont_eat_groups,
-                &prefer_eat,
+                &mut spawner.commands,
+                &spawner.asset_server,
+                &mut spawner.rng.inner,
+                spawner.name_gen.generate().as_str(),
+                NewPerson {
+                    spawn_location: trans.translation,
+                    stomach_size_range: (
+                        measurements::Volume::from_milliliters(min_stomach_range),
+                        measurements::Volume::from_milliliters(max_stomach_range),
+                    ),
+                    speed,
+                    age: Duration::ZERO,
+                    wont_eat_groups: &wont_eat_groups,
+                    prefer_eat_groups: &prefer_eat,
+                    strategic_fraction: scenario.population.strategic_fraction,
+                    money: 0.0,
+                },
             );
 
-            commands.entity(entity).remove::<reproduction::Pregnant>();
+            spawner
+                .commands
+                .entity(entity)
+                .remove::<reproduction::Pregnant>();
         }
     }
 }
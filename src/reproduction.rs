@@ -1,10 +1,15 @@
 use std::time::Duration;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::{collision, sim_time::SimTime};
+use crate::{
+    collision,
+    save::{EntityRemap, RemapEntities},
+    sim_time::SimTime,
+};
 
-#[derive(Debug, Component)]
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct Reproductive {
     pub reproduction_timer: Timer,
 }
@@ -36,12 +41,20 @@ impl Default for Reproductive {
     }
 }
 
-#[derive(Debug, Component)]
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct Pregnant {
     pub parents: [Entity; 2],
     pub pregnancy_timer: Timer,
 }
 
+impl RemapEntities for Pregnant {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        for parent in &mut self.parents {
+            *parent = remap.get(*parent);
+        }
+    }
+}
+
 pub fn get_pregnancy_duration(rng: &mut impl rand::Rng) -> Duration {
     const MIN_DURATION: Duration = Duration::from_secs(7);
     const MAX_DURATION: Duration = Duration::from_secs(10);
@@ -82,14 +95,10 @@ impl ReproductiveZoneBundle {
     }
 }
 
-pub fn reproductive_timer_tick_system(
-    time: Res<Time>,
-    sim_time: Res<SimTime>,
-    mut q: Query<&mut Reproductive>,
-) {
+pub fn reproductive_timer_tick_system(sim_time: Res<SimTime>, mut q: Query<&mut Reproductive>) {
     for mut reproductive in &mut q {
         if !reproductive.reproduction_timer.finished() {
-            reproductive.reproduction_timer.tick(sim_time.delta(&time));
+            reproductive.reproduction_timer.tick(sim_time.delta());
         }
     }
 }
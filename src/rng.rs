@@ -1,39 +1,27 @@
 use bevy::prelude::*;
 use rand::SeedableRng;
 use rand_xoshiro::Xoshiro256StarStar;
+use serde::{Deserialize, Serialize};
 
-#[derive(Resource)]
+#[derive(Clone, Resource, Serialize, Deserialize)]
 pub struct Rng {
     pub inner: Xoshiro256StarStar,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum Seed {
-    Number(u64),
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            inner: Xoshiro256StarStar::seed_from_u64(seed),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct RngPlugin {
-    seed: Option<Seed>,
-}
-
-impl RngPlugin {
-    pub fn new() -> Self {
-        Self { seed: None }
-    }
-
-    pub fn with_seed(seed: Seed) -> Self {
-        Self { seed: Some(seed) }
-    }
-}
+pub struct RngPlugin;
 
 impl Plugin for RngPlugin {
     fn build(&self, app: &mut App) {
-        let rng = match &self.seed {
-            Some(Seed::Number(num)) => Xoshiro256StarStar::seed_from_u64(*num),
-            None => Xoshiro256StarStar::seed_from_u64(0),
-        };
-
-        app.insert_resource(Rng { inner: rng });
+        // Stands in until the scenario's seed, or a saved rng, replaces it
+        app.insert_resource(Rng::new(0));
     }
 }
@@ -0,0 +1,667 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    age::Age,
+    brain::Brain,
+    buildings::{
+        building::{Building, BuildingPlots, BuildingStatus},
+        construction::{ConstructionOutcome, ConstructionQueue},
+        farm::{self, Farm},
+        granary::{self, Granary},
+        house::{self, House},
+        mint::{self, Mint},
+        money_hole::{self, MoneyHole},
+        voting_center::VotingCenterBundle,
+    },
+    collision::{Collider, CollisionHolder, CollisionTimer},
+    death::{CheckOldAgeTimer, Mortal},
+    elections::{
+        election::{Election, ElectionBundle, ElectionHistory, ElectionTimer, HeldElection},
+        polling::{OpinionPoll, OpinionPolls},
+        voter::Voter,
+    },
+    energy::Energy,
+    goals::Goals,
+    grave::Grave,
+    headless::HeadlessConfig,
+    hunger::{FoodPreferences, Nutrition, Stomach},
+    money::{Treasury, Wallet},
+    movement::{MovementGoal, MovementSpeed, Velocity},
+    name::{self, NameGenerator},
+    people::{self, Person, PersonBundle},
+    reproduction::{Pregnant, Reproductive, ReproductiveZoneBundle},
+    rng,
+    scenario::Scenario,
+    sets::AppState,
+    shelter::RequiresHouse,
+    sim_time::SimTime,
+    tax::{TaxCollection, TaxPolicy},
+    upkeep::{UpkeepCost, UpkeepCostTimer},
+    world_stats::WorldStats,
+};
+
+// Bump whenever the layout of `SaveFile` changes so old saves are turned away rather than misread
+pub const SAVE_VERSION: u32 = 2;
+
+pub const QUICK_SAVE_PATH: &str = "saves/quicksave.voties";
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(std::io::Error),
+    Encode(rmp_serde::encode::Error),
+    Decode(rmp_serde::decode::Error),
+    Version { found: u32, expected: u32 },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(err) => write!(f, "{}", err),
+            SaveError::Encode(err) => write!(f, "couldn't encode save: {}", err),
+            SaveError::Decode(err) => write!(f, "couldn't decode save: {}", err),
+            SaveError::Version { found, expected } => {
+                write!(
+                    f,
+                    "save is version {} but only {} can be loaded",
+                    found, expected
+                )
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for SaveError {
+    fn from(err: std::io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+impl From<rmp_serde::encode::Error> for SaveError {
+    fn from(err: rmp_serde::encode::Error) -> Self {
+        SaveError::Encode(err)
+    }
+}
+
+impl From<rmp_serde::decode::Error> for SaveError {
+    fn from(err: rmp_serde::decode::Error) -> Self {
+        SaveError::Decode(err)
+    }
+}
+
+// Entities are saved with the ids they had, loading hands out new ones
+#[derive(Debug, Default)]
+pub struct EntityRemap {
+    map: HashMap<Entity, Entity>,
+    ghosts: u32,
+}
+
+impl EntityRemap {
+    pub fn insert(&mut self, saved: Entity, loaded: Entity) {
+        self.map.insert(saved, loaded);
+    }
+
+    pub fn get(&mut self, saved: Entity) -> Entity {
+        if let Some(loaded) = self.map.get(&saved) {
+            return *loaded;
+        }
+
+        // Whatever this pointed at was already gone when the world was saved. It gets an id
+        // nothing will ever spawn with so ballots cast by the dead still count separately.
+        self.ghosts += 1;
+        let ghost = Entity::from_raw(Entity::PLACEHOLDER.index() - self.ghosts);
+        self.map.insert(saved, ghost);
+        ghost
+    }
+}
+
+pub trait RemapEntities {
+    fn remap_entities(&mut self, remap: &mut EntityRemap);
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedPerson {
+    entity: Entity,
+    translation: Vec3,
+    stomach: Stomach,
+    energy: Energy,
+    food_preferences: FoodPreferences,
+    movement_goal: MovementGoal,
+    movement_speed: MovementSpeed,
+    velocity: Velocity,
+    collision_holder: CollisionHolder,
+    mortal: Mortal,
+    age: Age,
+    name: name::Name,
+    reproductive: Reproductive,
+    voter: Voter,
+    goal: Goals,
+    requires_house: RequiresHouse,
+    pregnant: Option<Pregnant>,
+    #[serde(default)]
+    wallet: Wallet,
+    #[serde(default)]
+    nutrition: Nutrition,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedBuilding {
+    entity: Entity,
+    building: Building,
+    translation: Vec3,
+    status: Option<BuildingStatus>,
+    upkeep: Option<UpkeepCost>,
+    collision_holder: Option<CollisionHolder>,
+    farm: Option<Farm>,
+    mint: Option<Mint>,
+    money_hole: Option<MoneyHole>,
+    house: Option<House>,
+    #[serde(default)]
+    granary: Option<Granary>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedElection {
+    entity: Entity,
+    name: name::Name,
+    election: Election,
+}
+
+// Results are worked out again from the ballots when loading
+#[derive(Serialize, Deserialize)]
+struct SavedHeldElection {
+    name: String,
+    election: Election,
+    construction: Vec<ConstructionOutcome>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SaveFile {
+    version: u32,
+    scenario: Scenario,
+    sim_time: SimTime,
+    rng: rng::Rng,
+    treasury: Treasury,
+    plots: BuildingPlots,
+    construction_queue: ConstructionQueue,
+    name_generator: NameGenerator,
+    world_stats: WorldStats,
+    election_timer: ElectionTimer,
+    check_old_age_timer: CheckOldAgeTimer,
+    collision_timer: CollisionTimer,
+    upkeep_cost_timer: UpkeepCostTimer,
+    #[serde(default)]
+    tax_policy: TaxPolicy,
+    #[serde(default)]
+    tax_collection: TaxCollection,
+    // Ordered by election so the same world always saves the same bytes
+    #[serde(default)]
+    opinion_polls: Vec<OpinionPoll>,
+    held_elections: Vec<SavedHeldElection>,
+    people: Vec<SavedPerson>,
+    buildings: Vec<SavedBuilding>,
+    elections: Vec<SavedElection>,
+    graves: Vec<Grave>,
+}
+
+impl SaveFile {
+    fn from_world(world: &mut World) -> Self {
+        let mut people = world
+            .query_filtered::<(
+                (Entity, &Transform, &Stomach, &Energy, &FoodPreferences),
+                (&MovementGoal, &MovementSpeed, &Velocity, &CollisionHolder),
+                (&Mortal, &Age, &name::Name, &Reproductive, &Voter, &Goals),
+                (&RequiresHouse, Option<&Pregnant>, &Wallet, &Nutrition),
+            ), With<Person>>()
+            .iter(world)
+            .map(
+                |(
+                    (entity, transform, stomach, energy, food_preferences),
+                    (movement_goal, movement_speed, velocity, collision_holder),
+                    (mortal, age, name, reproductive, voter, goal),
+                    (requires_house, pregnant, wallet, nutrition),
+                )| SavedPerson {
+                    entity,
+                    translation: transform.translation,
+                    stomach: stomach.clone(),
+                    energy: *energy,
+                    food_preferences: food_preferences.clone(),
+                    movement_goal: movement_goal.clone(),
+                    movement_speed: movement_speed.clone(),
+                    velocity: velocity.clone(),
+                    collision_holder: collision_holder.clone(),
+                    mortal: mortal.clone(),
+                    age: age.clone(),
+                    name: name.clone(),
+                    reproductive: reproductive.clone(),
+                    voter: *voter,
+                    goal: goal.clone(),
+                    requires_house: requires_house.clone(),
+                    pregnant: pregnant.cloned(),
+                    wallet: wallet.clone(),
+                    nutrition: nutrition.clone(),
+                },
+            )
+            .collect::<Vec<_>>();
+        // Spawned back in the same order so the new ids sort the same way as the old ones
+        people.sort_by_key(|person| person.entity);
+
+        let mut buildings = world
+            .query::<(
+                Entity,
+                &Building,
+                &Transform,
+                Option<&BuildingStatus>,
+                Option<&UpkeepCost>,
+                Option<&CollisionHolder>,
+                Option<&Farm>,
+                Option<&Mint>,
+                Option<&MoneyHole>,
+                Option<&House>,
+                Option<&Granary>,
+            )>()
+            .iter(world)
+            .map(
+                |(
+                    entity,
+                    building,
+                    transform,
+                    status,
+                    upkeep,
+                    collision_holder,
+                    farm,
+                    mint,
+                    money_hole,
+                    house,
+                    granary,
+                )| SavedBuilding {
+                    entity,
+                    building: building.clone(),
+                    translation: transform.translation,
+                    status: status.cloned(),
+                    upkeep: upkeep.cloned(),
+                    collision_holder: collision_holder.cloned(),
+                    farm: farm.cloned(),
+                    mint: mint.cloned(),
+                    money_hole: money_hole.cloned(),
+                    house: house.cloned(),
+                    granary: granary.cloned(),
+                },
+            )
+            .collect::<Vec<_>>();
+        buildings.sort_by_key(|building| building.entity);
+
+        let mut elections = world
+            .query::<(Entity, &name::Name, &Election)>()
+            .iter(world)
+            .map(|(entity, name, election)| SavedElection {
+                entity,
+                name: name.clone(),
+                election: election.clone(),
+            })
+            .collect::<Vec<_>>();
+        elections.sort_by_key(|election| election.entity);
+
+        let graves = world
+            .query::<&Grave>()
+            .iter(world)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut opinion_polls = world
+            .resource::<OpinionPolls>()
+            .latest
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        opinion_polls.sort_by_key(|poll| poll.election);
+
+        let held_elections = world
+            .resource::<ElectionHistory>()
+            .held_elections
+            .iter()
+            .map(|held_election| SavedHeldElection {
+                name: held_election.name.clone(),
+                election: held_election.election.clone(),
+                construction: held_election.construction.clone(),
+            })
+            .collect();
+
+        Self {
+            version: SAVE_VERSION,
+            scenario: world.resource::<Scenario>().clone(),
+            sim_time: world.resource::<SimTime>().clone(),
+            rng: world.resource::<rng::Rng>().clone(),
+            treasury: world.resource::<Treasury>().clone(),
+            plots: world.resource::<BuildingPlots>().clone(),
+            construction_queue: world.resource::<ConstructionQueue>().clone(),
+            name_generator: world.resource::<NameGenerator>().clone(),
+            world_stats: world.resource::<WorldStats>().clone(),
+            election_timer: world.resource::<ElectionTimer>().clone(),
+            check_old_age_timer: world.resource::<CheckOldAgeTimer>().clone(),
+            collision_timer: world.resource::<CollisionTimer>().clone(),
+            upkeep_cost_timer: world.resource::<UpkeepCostTimer>().clone(),
+            tax_policy: world.resource::<TaxPolicy>().clone(),
+            tax_collection: world.resource::<TaxCollection>().clone(),
+            opinion_polls,
+            held_elections,
+            people,
+            buildings,
+            elections,
+            graves,
+        }
+    }
+
+    fn read(path: &Path) -> Result<Self, SaveError> {
+        let bytes = std::fs::read(path)?;
+
+        // Checked on its own first, a save from another version likely won't decode at all
+        let header: SaveHeader = rmp_serde::from_slice(&bytes)?;
+        if header.version != SAVE_VERSION {
+            return Err(SaveError::Version {
+                found: header.version,
+                expected: SAVE_VERSION,
+            });
+        }
+
+        Ok(rmp_serde::from_slice(&bytes)?)
+    }
+
+    fn write(&self, path: &Path) -> Result<(), SaveError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, rmp_serde::to_vec_named(self)?)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SaveWorldEvent {
+    pub path: PathBuf,
+}
+
+pub fn save_world_system(world: &mut World) {
+    let requests = world
+        .resource_mut::<Events<SaveWorldEvent>>()
+        .drain()
+        .collect::<Vec<_>>();
+    if requests.is_empty() {
+        return;
+    }
+
+    let headless = world.contains_resource::<HeadlessConfig>();
+    let save = SaveFile::from_world(world);
+    for request in requests {
+        match save.write(&request.path) {
+            Ok(()) => info!("Saved world to {}", request.path.display()),
+            Err(err) => report_failure(
+                headless,
+                format!(
+                    "Failed to save world to {}: {}",
+                    request.path.display(),
+                    err
+                ),
+            ),
+        }
+    }
+}
+
+// Headless runs have no log to read afterwards so a failure has to end the run
+fn report_failure(headless: bool, message: String) {
+    if headless {
+        eprintln!("{}", message);
+        std::process::exit(1);
+    }
+
+    error!("{}", message);
+}
+
+// Present until the world has been loaded, setting up a fresh world waits on it being gone
+#[derive(Debug, Clone, Resource)]
+pub struct LoadWorld {
+    pub path: PathBuf,
+}
+
+impl LoadWorld {
+    /// Returns `None` unless `--load <path>` was passed.
+    pub fn from_args(args: impl Iterator<Item = String>) -> Option<Self> {
+        let mut args = args.skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--load" {
+                let path = args.next().expect("--load requires a path to a save file");
+                return Some(Self { path: path.into() });
+            }
+        }
+
+        None
+    }
+}
+
+fn spawn_saved_building(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    saved: &SavedBuilding,
+) -> Entity {
+    let location = saved.translation.truncate();
+
+    match &saved.building {
+        Building::VotingCenter => commands
+            .spawn(VotingCenterBundle::new(asset_server, location))
+            .id(),
+        Building::Farm(produces) => farm::spawn(
+            commands,
+            asset_server,
+            produces.clone(),
+            saved.farm.as_ref().unwrap().production_timer.duration(),
+            location,
+        ),
+        Building::ReproductiveZone => commands
+            .spawn(ReproductiveZoneBundle::new(asset_server, location))
+            .id(),
+        Building::MoneyHole => money_hole::spawn(
+            commands,
+            asset_server,
+            saved.money_hole.as_ref().unwrap().capacity,
+            location,
+        ),
+        Building::Mint => {
+            let mint = saved.mint.as_ref().unwrap();
+            mint::spawn(
+                commands,
+                asset_server,
+                mint.money_per_cycle,
+                mint.timer.duration(),
+                location,
+            )
+        }
+        Building::House(dwellings) => house::spawn(commands, asset_server, *dwellings, location),
+        Building::Granary => granary::spawn(
+            commands,
+            asset_server,
+            saved.granary.as_ref().unwrap().capacity,
+            location,
+        ),
+    }
+}
+
+pub fn load_world_system(
+    mut commands: Commands,
+    mut state: ResMut<NextState<AppState>>,
+    asset_server: Res<AssetServer>,
+    load: Res<LoadWorld>,
+    headless: Option<Res<HeadlessConfig>>,
+) {
+    // Either way this is the only attempt, a failed load falls back to a fresh world unless headless
+    commands.remove_resource::<LoadWorld>();
+
+    let save = match SaveFile::read(&load.path) {
+        Ok(save) => save,
+        Err(err) => {
+            report_failure(
+                headless.is_some(),
+                format!("Failed to load world from {}: {}", load.path.display(), err),
+            );
+            return;
+        }
+    };
+
+    info!("Loading world from {}", load.path.display());
+
+    // Every saved entity gets its new id before anything pointing at them is restored
+    let mut remap = EntityRemap::default();
+    let buildings = save
+        .buildings
+        .into_iter()
+        .map(|saved| {
+            let entity = spawn_saved_building(&mut commands, &asset_server, &saved);
+            remap.insert(saved.entity, entity);
+            (entity, saved)
+        })
+        .collect::<Vec<_>>();
+    let people = save
+        .people
+        .into_iter()
+        .map(|saved| {
+            let entity = commands.spawn_empty().id();
+            remap.insert(saved.entity, entity);
+            (entity, saved)
+        })
+        .collect::<Vec<_>>();
+    let elections = save
+        .elections
+        .into_iter()
+        .map(|saved| {
+            let entity = commands.spawn_empty().id();
+            remap.insert(saved.entity, entity);
+            (entity, saved)
+        })
+        .collect::<Vec<_>>();
+
+    for (entity, saved) in buildings {
+        let mut building = commands.entity(entity);
+        building.insert(saved.building);
+
+        if let Some(status) = saved.status {
+            building.insert(status);
+        }
+        if let Some(upkeep) = saved.upkeep {
+            building.insert(upkeep);
+        }
+        if let Some(mut collision_holder) = saved.collision_holder {
+            collision_holder.remap_entities(&mut remap);
+            building.insert(collision_holder);
+        }
+        if let Some(farm) = saved.farm {
+            building.insert(farm);
+        }
+        if let Some(mint) = saved.mint {
+            building.insert(mint);
+        }
+        if let Some(money_hole) = saved.money_hole {
+            building.insert(money_hole);
+        }
+        if let Some(mut house) = saved.house {
+            house.remap_entities(&mut remap);
+            building.insert(house);
+        }
+        if let Some(granary) = saved.granary {
+            building.insert(granary);
+        }
+    }
+
+    for (entity, mut saved) in people {
+        saved.collision_holder.remap_entities(&mut remap);
+        saved.goal.remap_entities(&mut remap);
+        saved.requires_house.remap_entities(&mut remap);
+
+        let mut person = commands.entity(entity);
+        person.insert(PersonBundle {
+            sprite: people::person_sprite(&asset_server, saved.translation),
+            stomach: saved.stomach,
+            energy: saved.energy,
+            food_preferences: saved.food_preferences,
+            movement_goal: saved.movement_goal,
+            movement_speed: saved.movement_speed,
+            velocity: saved.velocity,
+            person: Person,
+            collider: Collider,
+            collision_holder: saved.collision_holder,
+            mortal: saved.mortal,
+            age: saved.age,
+            brain: Brain,
+            name: saved.name,
+            reproductive: saved.reproductive,
+            voter: saved.voter,
+            goal: saved.goal,
+            requires_house: saved.requires_house,
+            wallet: saved.wallet,
+            nutrition: saved.nutrition,
+        });
+
+        if let Some(mut pregnant) = saved.pregnant {
+            pregnant.remap_entities(&mut remap);
+            person.insert(pregnant);
+        }
+    }
+
+    for (entity, mut saved) in elections {
+        saved.election.remap_entities(&mut remap);
+        commands.entity(entity).insert(ElectionBundle {
+            name: saved.name,
+            election: saved.election,
+        });
+    }
+
+    for grave in save.graves {
+        commands.spawn(grave);
+    }
+
+    // Restored rather than retaken, a fresh poll would draw from the rng the saved run didn't
+    let latest = save
+        .opinion_polls
+        .into_iter()
+        .map(|mut poll| {
+            poll.remap_entities(&mut remap);
+            (poll.election, poll)
+        })
+        .collect();
+
+    // Past ballots keep their saved voter ids, they are only used to order tie breaks
+    let held_elections = save
+        .held_elections
+        .into_iter()
+        .map(|saved| HeldElection::new(&saved.name, saved.election, saved.construction))
+        .collect();
+
+    // The world carries on under the rules it was saved with
+    commands.insert_resource(save.scenario);
+    commands.insert_resource(save.sim_time);
+    commands.insert_resource(save.rng);
+    commands.insert_resource(save.treasury);
+    commands.insert_resource(save.plots);
+    commands.insert_resource(save.construction_queue);
+    commands.insert_resource(save.name_generator);
+    commands.insert_resource(save.world_stats);
+    commands.insert_resource(save.election_timer);
+    commands.insert_resource(save.check_old_age_timer);
+    commands.insert_resource(save.collision_timer);
+    commands.insert_resource(save.upkeep_cost_timer);
+    commands.insert_resource(save.tax_policy);
+    commands.insert_resource(save.tax_collection);
+    commands.insert_resource(ElectionHistory { held_elections });
+    commands.insert_resource(OpinionPolls { latest });
+
+    state.set(AppState::SettingUpUi);
+}
@@ -0,0 +1,80 @@
+use bevy::{prelude::*, reflect::TypeUuid};
+use serde::{Deserialize, Serialize};
+use voties::tally::ElectionType;
+
+use crate::{assets, buildings::construction::ConstructionPolicy, money::Money};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Population {
+    pub count: usize,
+    pub min_stomach_size_ml: f64,
+    pub max_stomach_size_ml: f64,
+    pub min_speed: f32,
+    pub max_speed: f32,
+    // Share of people, including those born later, who vote strategically
+    #[serde(default)]
+    pub strategic_fraction: f32,
+    // What everyone there at the start has in their wallet, those born later start with nothing
+    #[serde(default)]
+    pub starting_money: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartingBuildings {
+    pub farms: usize,
+    pub houses: usize,
+    pub reproductive_zones: usize,
+    pub mints: usize,
+    pub money_holes: usize,
+    #[serde(default)]
+    pub granaries: usize,
+    // Spread out evenly between the other buildings
+    pub voting_centers: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElectionCadence {
+    // Time between one election starting and the next
+    pub interval_seconds: f32,
+    // How long each election takes votes before closing
+    pub open_seconds: f32,
+    // Each election is held under one of these picked at random
+    pub types: Vec<ElectionType>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TypeUuid, Resource)]
+#[uuid = "5f0c1b8e-3d2a-4c55-9a61-7b2f4e0d9c13"]
+pub struct Scenario {
+    // Left out for a different world every run
+    pub seed: Option<u64>,
+    pub population: Population,
+    pub buildings: StartingBuildings,
+    pub elections: ElectionCadence,
+    #[serde(default)]
+    pub construction_policy: ConstructionPolicy,
+}
+
+// Which scenario asset to set the world up from
+#[derive(Debug, Clone, Resource)]
+pub struct ScenarioFile {
+    pub path: String,
+}
+
+impl ScenarioFile {
+    /// Uses `--scenario <path>` if passed, relative to the assets folder.
+    pub fn from_args(args: impl Iterator<Item = String>) -> Self {
+        let mut args = args.skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--scenario" {
+                let path = args
+                    .next()
+                    .expect("--scenario requires a path to a scenario file");
+                return Self { path };
+            }
+        }
+
+        Self {
+            path: assets::DEFAULT_SCENARIO_FILE.to_owned(),
+        }
+    }
+}
@@ -1,26 +1,32 @@
 use std::time::Duration;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::sim_time::SimTime;
+use crate::{
+    save::{EntityRemap, RemapEntities},
+    sim_time::SimTime,
+};
 
-#[derive(Debug, Default, Component)]
+#[derive(Debug, Clone, Default, Component, Serialize, Deserialize)]
 pub struct RequiresHouse {
     pub shelter: Option<Entity>,
     pub homeless_for: Duration,
 }
 
-pub fn tick_homeless_system(
-    time: Res<Time>,
-    sim_time: Res<SimTime>,
-    mut query: Query<&mut RequiresHouse>,
-) {
+impl RemapEntities for RequiresHouse {
+    fn remap_entities(&mut self, remap: &mut EntityRemap) {
+        self.shelter = self.shelter.map(|shelter| remap.get(shelter));
+    }
+}
+
+pub fn tick_homeless_system(sim_time: Res<SimTime>, mut query: Query<&mut RequiresHouse>) {
     for mut requires_house in &mut query {
         if requires_house.shelter.is_some() {
             requires_house.homeless_for = Duration::ZERO;
             continue;
         }
 
-        requires_house.homeless_for += sim_time.delta(&time);
+        requires_house.homeless_for += sim_time.delta();
     }
 }
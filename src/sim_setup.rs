@@ -1,57 +1,68 @@
 use std::{collections::HashSet, time::Duration};
 
 use bevy::prelude::*;
-use lazy_static::lazy_static;
 use rand::{seq::SliceRandom, Rng};
 
 use crate::{
     buildings::building::{Building, BuildingPlots},
+    buildings::construction::ConstructionQueue,
     buildings::{
         building,
         house::{self, MAX_DWELLINGS, MIN_DWELLINGS},
         mint, money_hole,
         voting_center::VotingCenterBundle,
     },
-    death, hunger, name,
-    people::{self, create_person},
-    rng, sim_time,
+    death,
+    elections::election::ElectionTimer,
+    hunger, name,
+    people::{self, create_person, NewPerson, PersonSpawner},
+    scenario::{Population, Scenario},
+    sim_time,
     upkeep::setup_upkeep,
     AppState,
 };
 
 pub fn setting_up_world(
-    mut commands: Commands,
+    spawner: PersonSpawner,
     mut state: ResMut<NextState<AppState>>,
-    mut rng: ResMut<rng::Rng>,
     mut plots: ResMut<BuildingPlots>,
     food_collection: Res<hunger::FoodCollection>,
-    asset_server: Res<AssetServer>,
-    time: Res<Time>,
-    mut name_gen: ResMut<name::NameGenerator>,
+    scenario: Res<Scenario>,
 ) {
+    let PersonSpawner {
+        mut commands,
+        asset_server,
+        mut rng,
+        mut name_gen,
+    } = spawner;
+
     setup_upkeep(&mut commands);
 
     sim_time::setup(&mut commands);
 
+    commands.insert_resource(ElectionTimer(Timer::from_seconds(
+        scenario.elections.interval_seconds,
+        TimerMode::Repeating,
+    )));
+
+    commands.insert_resource(ConstructionQueue {
+        policy: scenario.construction_policy,
+        ..default()
+    });
+
     create_starting_people(
         &mut commands,
-        &time,
         &asset_server,
         &mut rng.inner,
         &mut name_gen,
+        &scenario.population,
     );
 
     let mut buildings_to_create: Vec<Building> = vec![];
-
-    const FARMS_COUNT: usize = PEOPLE_COUNT / 15 + 1;
-    const HOUSES_COUNT: usize = PEOPLE_COUNT / (MAX_DWELLINGS - MIN_DWELLINGS) as usize + 1;
-    const REPRODUCTIVE_ZONE_COUNT: usize = PEOPLE_COUNT / 100 + 1;
-    const MINTS_COUNT: usize =
-        FARMS_COUNT / 3 + HOUSES_COUNT / 10 + REPRODUCTIVE_ZONE_COUNT / 10 + 1;
-    const MONEY_HOLES_COUNT: usize = MINTS_COUNT / 10 + 1;
+    let starting_buildings = &scenario.buildings;
 
     // Add Farms
-    for _ in 0..FARMS_COUNT {
+    for _ in 0..starting_buildings.farms {
         let food = food_collection
             .foods
             .choose(&mut rng.inner)
@@ -61,33 +72,38 @@ pub fn setting_up_world(
     }
 
     // Add housing
-    for _ in 0..HOUSES_COUNT {
+    for _ in 0..starting_buildings.houses {
         let dwellings = rng.inner.gen_range(MIN_DWELLINGS..MAX_DWELLINGS);
         buildings_to_create.push(Building::House(dwellings));
     }
 
     // Add Reproductive Zones
-    for _ in 0..REPRODUCTIVE_ZONE_COUNT {
+    for _ in 0..starting_buildings.reproductive_zones {
         buildings_to_create.push(Building::ReproductiveZone);
     }
 
     // Add Mints
-    for _ in 0..MINTS_COUNT {
+    for _ in 0..starting_buildings.mints {
         buildings_to_create.push(Building::Mint);
     }
 
     // Add Money Holes
-    for _ in 0..MONEY_HOLES_COUNT {
+    for _ in 0..starting_buildings.money_holes {
         buildings_to_create.push(Building::MoneyHole);
     }
 
+    for _ in 0..starting_buildings.granaries {
+        buildings_to_create.push(Building::Granary);
+    }
+
     buildings_to_create.shuffle(&mut rng.inner);
 
     // Add voting centers
-    let voting_center_count = buildings_to_create.len() / 50 + 1;
+    let voting_center_spacing =
+        buildings_to_create.len() / starting_buildings.voting_centers.max(1);
 
-    for i in 0..voting_center_count {
-        buildings_to_create.insert(i * 50, Building::VotingCenter);
+    for i in 0..starting_buildings.voting_centers {
+        buildings_to_create.insert(i * (voting_center_spacing + 1), Building::VotingCenter);
     }
 
     for building in buildings_to_create {
@@ -97,27 +113,22 @@ pub fn setting_up_world(
     state.set(AppState::SettingUpUi);
 }
 
-const PEOPLE_COUNT: usize = 100;
-
-lazy_static! {
-    static ref MIN_STOMACH_SIZE_ML: measurements::Volume = measurements::Volume::from_liters(2.0);
-    static ref MAX_STOMACH_SIZE_ML: measurements::Volume = measurements::Volume::from_liters(3.0);
-}
-
-const MAX_SPEED: f32 = 200.0;
-const MIN_SPEED: f32 = 50.0;
-
 fn create_starting_people(
     commands: &mut Commands,
-    time: &Time,
     asset_server: &AssetServer,
     rng: &mut impl rand::Rng,
     name_gen: &mut name::NameGenerator,
+    population: &Population,
 ) {
-    for _ in 0..PEOPLE_COUNT {
+    let stomach_size_range = (
+        measurements::Volume::from_milliliters(population.min_stomach_size_ml),
+        measurements::Volume::from_milliliters(population.max_stomach_size_ml),
+    );
+
+    for _ in 0..population.count {
         let age =
             Duration::from_secs(rng.gen_range(0..(death::OLD_AGE_DEATH_THRESHOLD.as_secs() / 5)));
-        let speed = rng.gen_range(MIN_SPEED..MAX_SPEED);
+        let speed = rng.gen_range(population.min_speed..population.max_speed);
 
         let food_group_count = people::wont_eat_count(rng);
         let mut wont_eat = HashSet::new();
@@ -133,16 +144,19 @@ fn create_starting_people(
 
         create_person(
             commands,
-            time,
             asset_server,
             rng,
             name_gen.generate().as_str(),
-            spawn_location,
-            (MIN_STOMACH_SIZE_ML.clone(), MAX_STOMACH_SIZE_ML.clone()),
-            speed,
-            age,
-            &wont_eat_food_groups,
-            &prefer_eat_groups,
+            NewPerson {
+                spawn_location,
+                stomach_size_range,
+                speed,
+                age,
+                wont_eat_groups: &wont_eat_food_groups,
+                prefer_eat_groups: &prefer_eat_groups,
+                strategic_fraction: population.strategic_fraction,
+                money: population.starting_money,
+            },
         );
     }
 }
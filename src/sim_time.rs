@@ -1,11 +1,33 @@
 use std::time::Duration;
 
-use bevy::prelude::*;
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+use bevy_enum_filter::EnumFilter;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Resource)]
+pub const MIN_MULTIPLIER: f32 = 0.1;
+pub const MAX_MULTIPLIER: f32 = 20.0;
+
+// Every tick advances the world by exactly this much no matter the frame rate
+pub const TICK: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+// Stops a slow frame from snowballing into even slower frames
+const MAX_TICKS_PER_FRAME: u32 = 240;
+const UNTHROTTLED_TICKS_PER_FRAME: u32 = 60;
+
+/// Runs one fixed step of the simulation, see [`run_sim_schedule_system`].
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SimSchedule;
+
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
 pub struct SimTime {
     multiplier: f32,
     current_time: Duration,
+    ticks: u64,
+    // Wall clock bookkeeping, a loaded world starts pacing itself afresh
+    #[serde(skip)]
+    unspent: Duration,
+    #[serde(skip)]
+    unthrottled: bool,
 }
 
 impl Default for SimTime {
@@ -13,6 +35,9 @@ impl Default for SimTime {
         Self {
             multiplier: 1.0,
             current_time: Duration::ZERO,
+            ticks: 0,
+            unspent: Duration::ZERO,
+            unthrottled: false,
         }
     }
 }
@@ -23,32 +48,83 @@ impl SimTime {
     }
 
     pub fn add_to_multiplier(&mut self, to_add: f32) {
-        self.multiplier += to_add;
-        self.multiplier = self.multiplier.clamp(0.1, 20.0);
+        self.set_multiplier(self.multiplier + to_add);
+    }
+
+    pub fn set_multiplier(&mut self, multiplier: f32) {
+        self.multiplier = multiplier.clamp(MIN_MULTIPLIER, MAX_MULTIPLIER);
     }
 
-    pub fn delta(&self, time: &Time) -> Duration {
-        time.delta().mul_f32(self.multiplier)
+    /// Stop keeping pace with the wall clock and run ticks as fast as possible.
+    pub fn unthrottle(&mut self) {
+        self.unthrottled = true;
     }
 
-    pub fn delta_seconds(&self, time: &Time) -> f32 {
-        time.delta_seconds() * self.multiplier
+    pub fn delta(&self) -> Duration {
+        TICK
     }
 
-    pub fn delta_seconds_f64(&self, time: &Time) -> f64 {
-        time.delta_seconds_f64() * self.multiplier as f64
+    pub fn delta_seconds(&self) -> f32 {
+        TICK.as_secs_f32()
+    }
+
+    pub fn delta_seconds_f64(&self) -> f64 {
+        TICK.as_secs_f64()
     }
 
     pub fn elapsed(&self) -> Duration {
         self.current_time
     }
+
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    fn ticks_for_frame(&mut self, frame_delta: Duration) -> u32 {
+        if self.unthrottled {
+            return UNTHROTTLED_TICKS_PER_FRAME;
+        }
+
+        self.unspent += frame_delta.mul_f32(self.multiplier);
+
+        let ticks = (self.unspent.as_nanos() / TICK.as_nanos()) as u32;
+        if ticks > MAX_TICKS_PER_FRAME {
+            self.unspent = Duration::ZERO;
+            return MAX_TICKS_PER_FRAME;
+        }
+
+        self.unspent -= TICK * ticks;
+        ticks
+    }
 }
 
 pub fn setup(commands: &mut Commands) {
     commands.insert_resource(SimTime::new());
 }
 
-pub fn tick_sim_time_system(time: Res<Time>, mut sim_time: ResMut<SimTime>) {
-    let delta = sim_time.delta(&time);
+pub fn tick_sim_time_system(mut sim_time: ResMut<SimTime>) {
+    let delta = sim_time.delta();
     sim_time.current_time += delta;
+    sim_time.ticks += 1;
+}
+
+/// Runs as many fixed ticks as the frame time and multiplier allow.
+/// Faster frames just run fewer ticks so the same seed always plays out the same.
+pub fn run_sim_schedule_system(world: &mut World) {
+    let frame_delta = world.resource::<Time>().delta();
+    let ticks = world.resource_mut::<SimTime>().ticks_for_frame(frame_delta);
+
+    for _ in 0..ticks {
+        world.run_schedule(SimSchedule);
+    }
+}
+
+// Same job as `add_enum_filter` but once per tick rather than once per frame
+pub fn update_enum_filter_system<T: EnumFilter>(
+    mut commands: Commands,
+    query: Query<(Entity, &T), Changed<T>>,
+) {
+    for (entity, value) in &query {
+        T::set_marker(&mut commands.entity(entity), value);
+    }
 }
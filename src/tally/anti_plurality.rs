@@ -1,28 +1,30 @@
+use serde::Serialize;
+
 use super::{
-    election::{ElectionOption, ElectionTypeResult},
     voting_methods::{
         fill, vote_bundle, LeastFavoriteSingleOptionBallot, OptionRating, VoteBundle,
     },
+    Candidate, ElectionTypeResult,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VoteTally {
     option_index: usize,
     votes: usize,
 }
 
-#[derive(Debug, Clone)]
-pub struct AntiPluralityResult {
-    pub winner: ElectionOption,
+#[derive(Debug, Clone, Serialize)]
+pub struct AntiPluralityResult<C> {
+    pub winner: C,
     pub total_votes: usize,
     pub bundles: Vec<VoteBundle<LeastFavoriteSingleOptionBallot>>,
     pub vote_tally: Vec<VoteTally>,
 }
 
-pub fn result(
-    options: &[ElectionOption],
+pub fn result<C: Candidate>(
+    options: &[C],
     option_ratings: &Vec<&Vec<OptionRating>>,
-) -> ElectionTypeResult {
+) -> ElectionTypeResult<C> {
     let votes = fill::<LeastFavoriteSingleOptionBallot>(option_ratings);
 
     let bundles = vote_bundle(&votes);
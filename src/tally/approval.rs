@@ -1,17 +1,19 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+use serde::Serialize;
 
 use super::{
-    election::{ElectionImpl, ElectionOption, ElectionTypeResult},
     voting_methods::{
         fill, vote_bundle, MultipleOptionBallot, OptionRating, VoteBundle, VoteCount,
     },
+    Candidate, ElectionImpl, ElectionTypeResult,
 };
 
-#[derive(Debug, Clone)]
-pub struct ApprovalResult {
-    pub winner: ElectionOption,
+#[derive(Debug, Clone, Serialize)]
+pub struct ApprovalResult<C> {
+    pub winner: C,
     pub total_votes: usize,
-    pub vote_count: Vec<VoteCount>,
+    pub vote_count: Vec<VoteCount<C>>,
     pub bundles: Vec<VoteBundle<MultipleOptionBallot>>,
 }
 
@@ -19,15 +21,15 @@ pub struct ApprovalResult {
 pub struct Approval;
 
 impl ElectionImpl for Approval {
-    fn result(
-        options: &[ElectionOption],
+    fn result<C: Candidate>(
+        options: &[C],
         option_ratings: &Vec<&Vec<OptionRating>>,
-    ) -> ElectionTypeResult {
+    ) -> ElectionTypeResult<C> {
         let votes = fill::<MultipleOptionBallot>(option_ratings);
 
         let bundles = vote_bundle(&votes);
 
-        let mut vote_count = HashMap::<usize, i64>::new();
+        let mut vote_count = BTreeMap::<usize, i64>::new();
         for i in 0..options.len() {
             vote_count.insert(i, 0);
         }
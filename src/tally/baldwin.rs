@@ -0,0 +1,98 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
+use super::{
+    borda::{self, BordaPoints},
+    preferential::Round,
+    voting_methods::{
+        fill, vote_bundle, MandatoryPreferentialBallot, OptionRating, VoteBundle, VoteCount,
+    },
+    Candidate, ElectionImpl, ElectionTypeResult,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BaldwinResult<C> {
+    pub winner: C,
+    pub total_votes: usize,
+    pub rounds: Vec<Round<C>>,
+    pub bundles: Vec<VoteBundle<MandatoryPreferentialBallot>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Baldwin;
+
+impl ElectionImpl for Baldwin {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+    ) -> ElectionTypeResult<C> {
+        let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+
+        let bundles = vote_bundle(&votes);
+
+        let mut rounds = Vec::new();
+        let mut eliminated_candidates = BTreeSet::new();
+        loop {
+            let score_tally = borda::tally(
+                options.len(),
+                &bundles,
+                &eliminated_candidates,
+                &BordaPoints::Standard,
+            );
+
+            let bottom = score_tally.iter().last().unwrap().option_index;
+
+            rounds.push(Round {
+                eliminated_candidates: eliminated_candidates.clone(),
+                vote_tally: score_tally
+                    .into_iter()
+                    .map(|tally| VoteCount {
+                        option: options[tally.option_index].clone(),
+                        votes: tally.score as i64,
+                    })
+                    .collect(),
+            });
+
+            if eliminated_candidates.len() == options.len() - 1 {
+                break;
+            }
+
+            eliminated_candidates.insert(bottom);
+        }
+
+        ElectionTypeResult::BaldwinResult(BaldwinResult {
+            winner: rounds.last().unwrap().vote_tally[0].option.clone(),
+            total_votes: votes.len(),
+            rounds,
+            bundles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{
+        tests::{borda_split, compromise_beats_condorcet_winner, winner},
+        ElectionType,
+    };
+
+    #[test]
+    fn finds_the_condorcet_winner_borda_misses() {
+        // Carol goes on 2 points, then Alice beats Bob 3-2
+        assert_eq!(
+            winner(
+                ElectionType::Baldwin,
+                3,
+                &compromise_beats_condorcet_winner()
+            ),
+            "Alice"
+        );
+    }
+
+    #[test]
+    fn recounts_after_every_elimination() {
+        // Alice goes on 9, then Bob on 6 against Carol's 7 and Dave's 8, then Dave beats Carol 5-2
+        assert_eq!(winner(ElectionType::Baldwin, 4, &borda_split()), "Dave");
+    }
+}
@@ -0,0 +1,207 @@
+use std::{cmp::Reverse, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    star, usual_judgment,
+    voting_methods::{OptionRating, ScoreBallot},
+    want_level, Candidate, ElectionType, ElectionTypeResult,
+};
+
+/// A ballot as it was filled in, candidates are indexes into the election's candidates.
+/// Indexes past the last candidate or given twice are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Ballot {
+    // Most preferred first, anyone left off is below everyone listed and tied with each other
+    Ranked(Vec<usize>),
+    // Everyone approved of
+    Approval(Vec<usize>),
+    // A score for each candidate in candidate order, from 0 up to `max`
+    Score { scores: Vec<u32>, max: u32 },
+}
+
+const RATING_SPAN: i32 = want_level::EXTREMELY_POSITIVE - want_level::EXTREMELY_NEGATIVE;
+
+// Each valid candidate once, in the order given
+fn listed(candidates: &[usize], candidate_count: usize) -> Vec<usize> {
+    let mut seen = vec![false; candidate_count];
+    candidates
+        .iter()
+        .copied()
+        .filter(|candidate| {
+            if *candidate >= candidate_count || seen[*candidate] {
+                return false;
+            }
+            seen[*candidate] = true;
+            true
+        })
+        .collect()
+}
+
+// Listed candidates first then everyone else
+fn with_unlisted(mut listed: Vec<usize>, candidate_count: usize) -> Vec<usize> {
+    let unlisted = (0..candidate_count)
+        .filter(|candidate| !listed.contains(candidate))
+        .collect::<Vec<_>>();
+    listed.extend(unlisted);
+    listed
+}
+
+impl Ballot {
+    /// The ballot as ratings most wanted first, which is what every method is counted from.
+    pub fn option_ratings(&self, candidate_count: usize) -> Vec<OptionRating> {
+        match self {
+            Ballot::Ranked(ranking) => {
+                let ranking = listed(ranking, candidate_count);
+                let ranked_count = ranking.len();
+                let last = (candidate_count.max(2) - 1) as i32;
+                with_unlisted(ranking, candidate_count)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(rank, option_index)| OptionRating {
+                        option_index,
+                        rating: if rank < ranked_count {
+                            want_level::EXTREMELY_POSITIVE - RATING_SPAN * rank as i32 / last
+                        } else {
+                            OptionRating::UNRANKED
+                        },
+                    })
+                    .collect()
+            }
+            Ballot::Approval(approved) => {
+                let approved = listed(approved, candidate_count);
+                let approved_count = approved.len();
+                with_unlisted(approved, candidate_count)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, option_index)| OptionRating {
+                        option_index,
+                        rating: if i < approved_count {
+                            want_level::EXTREMELY_POSITIVE
+                        } else {
+                            want_level::EXTREMELY_NEGATIVE
+                        },
+                    })
+                    .collect()
+            }
+            Ballot::Score { scores, max } => {
+                let max = (*max).max(1) as i32;
+                let mut ratings = (0..candidate_count)
+                    .map(|option_index| {
+                        let score = scores.get(option_index).copied().unwrap_or(0) as i32;
+                        OptionRating {
+                            option_index,
+                            rating: want_level::EXTREMELY_NEGATIVE
+                                + RATING_SPAN * score.min(max) / max,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                // Stable so equal scores stay in candidate order
+                ratings.sort_by_key(|rating| Reverse(rating.rating));
+                ratings
+            }
+        }
+    }
+}
+
+// The scores exactly as given, as long as every ballot is a score ballot
+fn score_ballots<const N: usize>(
+    ballots: &[&Ballot],
+    candidate_count: usize,
+) -> Option<Vec<ScoreBallot<N>>> {
+    ballots
+        .iter()
+        .map(|ballot| match ballot {
+            Ballot::Score { scores, max } => Some(ScoreBallot {
+                votes: (0..candidate_count)
+                    .map(|candidate| scores.get(candidate).copied().unwrap_or(0).min(*max) as i32)
+                    .collect(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// `count` copies of each ranking, for writing out an election by hand.
+#[cfg(test)]
+pub fn ranked(rankings: &[(usize, &[usize])]) -> Vec<Ballot> {
+    rankings
+        .iter()
+        .flat_map(|(count, ranking)| vec![Ballot::Ranked(ranking.to_vec()); *count])
+        .collect()
+}
+
+/// `count` copies of each set of approved candidates, for writing out an election by hand.
+#[cfg(test)]
+pub fn approved(approvals: &[(usize, &[usize])]) -> Vec<Ballot> {
+    approvals
+        .iter()
+        .flat_map(|(count, approved)| vec![Ballot::Approval(approved.to_vec()); *count])
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TallyError {
+    // The method needs at least this many candidates standing
+    TooFewCandidates(usize),
+    // Every ballot was blank or named no one standing
+    NoValidBallots,
+}
+
+impl fmt::Display for TallyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TallyError::TooFewCandidates(needed) => {
+                write!(f, "needs at least {} candidates", needed)
+            }
+            TallyError::NoValidBallots => write!(f, "there are no valid ballots"),
+        }
+    }
+}
+
+/// Counts the ballots under `election_type`, `seats` only matters to multi winner methods.
+pub fn tally<C: Candidate>(
+    election_type: ElectionType,
+    candidates: &[C],
+    ballots: &[Ballot],
+    seats: usize,
+) -> Result<ElectionTypeResult<C>, TallyError> {
+    if candidates.len() < election_type.min_options() {
+        return Err(TallyError::TooFewCandidates(election_type.min_options()));
+    }
+
+    let ballots = ballots
+        .iter()
+        // A ranking with no one valid on it says nothing, rather than voting for the first candidate
+        .filter(|ballot| match ballot {
+            Ballot::Ranked(ranking) => !listed(ranking, candidates.len()).is_empty(),
+            _ => true,
+        })
+        .collect::<Vec<_>>();
+    if ballots.is_empty() {
+        return Err(TallyError::NoValidBallots);
+    }
+
+    // Score methods count the scores themselves rather than ratings squeezed back into their scale
+    match election_type {
+        ElectionType::Star => {
+            if let Some(votes) = score_ballots(&ballots, candidates.len()) {
+                return Ok(star::count(candidates, votes));
+            }
+        }
+        ElectionType::UsualJudgment => {
+            if let Some(votes) = score_ballots(&ballots, candidates.len()) {
+                return Ok(usual_judgment::count(candidates, votes));
+            }
+        }
+        _ => {}
+    }
+
+    let votes = ballots
+        .iter()
+        .map(|ballot| ballot.option_ratings(candidates.len()))
+        .collect::<Vec<_>>();
+    let option_ratings = votes.iter().collect::<Vec<_>>();
+
+    Ok(election_type.result(candidates, &option_ratings, seats.min(candidates.len())))
+}
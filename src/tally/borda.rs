@@ -0,0 +1,130 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
+use super::{
+    voting_methods::{fill, vote_bundle, MandatoryPreferentialBallot, OptionRating, VoteBundle},
+    Candidate, ElectionType, ElectionTypeResult,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum BordaPoints {
+    // n - 1 points for a first preference down to 0 for last
+    Standard,
+    // 1 point for a first preference, 1/2 for second, 1/3 for third and so on
+    Dowdall,
+    // Points by rank, ranks past the end get nothing
+    Custom(Vec<f64>),
+}
+
+impl BordaPoints {
+    pub fn points(&self, rank: usize, option_count: usize) -> f64 {
+        match self {
+            BordaPoints::Standard => (option_count - rank - 1) as f64,
+            BordaPoints::Dowdall => 1.0 / (rank + 1) as f64,
+            BordaPoints::Custom(points) => points.get(rank).cloned().unwrap_or(0.0),
+        }
+    }
+
+    pub fn election_type(&self) -> ElectionType {
+        match self {
+            BordaPoints::Dowdall => ElectionType::Dowdall,
+            _ => ElectionType::Borda,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BordaTally {
+    pub option_index: usize,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BordaResult<C> {
+    pub winner: C,
+    pub total_votes: usize,
+    pub points: BordaPoints,
+    pub score_tally: Vec<BordaTally>,
+    pub bundles: Vec<VoteBundle<MandatoryPreferentialBallot>>,
+}
+
+// Scores every option still standing, ballots are read as if the eliminated options were never on them
+pub fn tally(
+    option_count: usize,
+    bundles: &[VoteBundle<MandatoryPreferentialBallot>],
+    eliminated_candidates: &BTreeSet<usize>,
+    points: &BordaPoints,
+) -> Vec<BordaTally> {
+    let remaining = option_count - eliminated_candidates.len();
+
+    let mut score_tally = (0..option_count)
+        .filter(|option_index| !eliminated_candidates.contains(option_index))
+        .map(|option_index| BordaTally {
+            option_index,
+            score: 0.0,
+        })
+        .collect::<Vec<_>>();
+
+    for bundle in bundles {
+        let standing = bundle
+            .ballot
+            .votes
+            .iter()
+            .filter(|option_index| !eliminated_candidates.contains(option_index));
+
+        for (rank, option_index) in standing.enumerate() {
+            let tally = score_tally
+                .iter_mut()
+                .find(|tally| tally.option_index == *option_index)
+                .unwrap();
+            tally.score += points.points(rank, remaining) * bundle.votes as f64;
+        }
+    }
+
+    score_tally.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+
+    score_tally
+}
+
+pub fn result<C: Candidate>(
+    options: &[C],
+    option_ratings: &Vec<&Vec<OptionRating>>,
+    points: BordaPoints,
+) -> ElectionTypeResult<C> {
+    let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+
+    let bundles = vote_bundle(&votes);
+
+    let score_tally = tally(options.len(), &bundles, &BTreeSet::new(), &points);
+
+    ElectionTypeResult::BordaResult(BordaResult {
+        winner: options[score_tally[0].option_index].clone(),
+        total_votes: votes.len(),
+        points,
+        score_tally,
+        bundles,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{
+        tests::{borda_split, compromise_beats_condorcet_winner, winner},
+        ElectionType,
+    };
+
+    #[test]
+    fn misses_the_condorcet_winner() {
+        // Alice 6, Bob 7 and Carol 2 points
+        assert_eq!(
+            winner(ElectionType::Borda, 3, &compromise_beats_condorcet_winner()),
+            "Bob"
+        );
+    }
+
+    #[test]
+    fn counts_points_once() {
+        assert_eq!(winner(ElectionType::Borda, 4, &borda_split()), "Bob");
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use serde::Serialize;
+
+use super::{
+    voting_methods::{
+        fill, vote_bundle, MandatoryPreferentialBallot, OptionRating, VoteBundle, VoteCount,
+    },
+    Candidate, ElectionImpl, ElectionTypeResult,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CoombsRound<C> {
+    pub eliminated_candidates: BTreeSet<usize>,
+    pub vote_tally: Vec<VoteCount<C>>,
+    pub last_place_tally: Vec<VoteCount<C>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CoombsResult<C> {
+    pub winner: C,
+    pub total_votes: usize,
+    pub rounds: Vec<CoombsRound<C>>,
+    pub bundles: Vec<VoteBundle<MandatoryPreferentialBallot>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Coombs;
+
+// Option indexes still standing with their votes, most votes first
+fn standing(
+    tally: BTreeMap<usize, i64>,
+    eliminated_candidates: &BTreeSet<usize>,
+) -> Vec<(usize, i64)> {
+    let mut standing = tally
+        .into_iter()
+        .filter(|(k, _)| !eliminated_candidates.contains(k))
+        .collect::<Vec<_>>();
+
+    standing.sort_by_key(|(_, votes)| Reverse(*votes));
+
+    standing
+}
+
+fn to_vote_counts<C: Candidate>(options: &[C], standing: &[(usize, i64)]) -> Vec<VoteCount<C>> {
+    standing
+        .iter()
+        .map(|(k, v)| VoteCount {
+            option: options[*k].clone(),
+            votes: *v,
+        })
+        .collect()
+}
+
+impl ElectionImpl for Coombs {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+    ) -> ElectionTypeResult<C> {
+        let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+
+        let bundles = vote_bundle(&votes);
+
+        let mut rounds = Vec::new();
+        let mut eliminated_candidates = BTreeSet::new();
+        loop {
+            let mut first_place = BTreeMap::<usize, i64>::new();
+            let mut last_place = BTreeMap::<usize, i64>::new();
+            for i in 0..options.len() {
+                first_place.insert(i, 0);
+                last_place.insert(i, 0);
+            }
+
+            for bundle in &bundles {
+                let ranking = &bundle.ballot.votes;
+                let mut standing = ranking
+                    .iter()
+                    .filter(|option_index| !eliminated_candidates.contains(*option_index));
+                let unranked = (0..options.len())
+                    .filter(|option_index| {
+                        !eliminated_candidates.contains(option_index)
+                            && !ranking.contains(option_index)
+                    })
+                    .collect::<Vec<_>>();
+
+                if let Some(first) = standing.next() {
+                    *first_place.get_mut(first).unwrap() += bundle.votes as i64;
+                }
+
+                // Those left off are below everyone ranked, when there are several they tie for last
+                let last = match unranked.as_slice() {
+                    [] => standing.next_back(),
+                    [only] => Some(only),
+                    _ => None,
+                };
+                if let Some(last) = last {
+                    *last_place.get_mut(last).unwrap() += bundle.votes as i64;
+                }
+            }
+
+            let first_place = standing(first_place, &eliminated_candidates);
+            let last_place = standing(last_place, &eliminated_candidates);
+
+            // Ballots with no one left on them have run out and no longer count towards a majority
+            let continuing = first_place.iter().map(|(_, votes)| votes).sum::<i64>();
+            let (_, top_votes) = first_place[0];
+            let (most_disliked, _) = last_place[0];
+
+            rounds.push(CoombsRound {
+                eliminated_candidates: eliminated_candidates.clone(),
+                vote_tally: to_vote_counts(options, &first_place),
+                last_place_tally: to_vote_counts(options, &last_place),
+            });
+
+            if top_votes * 2 > continuing {
+                break;
+            }
+
+            if eliminated_candidates.len() == options.len() - 1 {
+                break;
+            }
+
+            eliminated_candidates.insert(most_disliked);
+        }
+
+        ElectionTypeResult::CoombsResult(CoombsResult {
+            winner: rounds.last().unwrap().vote_tally[0].option.clone(),
+            total_votes: votes.len(),
+            rounds,
+            bundles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::tally::{ballot, ElectionType, ElectionTypeResult};
+
+    #[test]
+    fn eliminates_the_candidate_not_their_namesake() {
+        let ballots = ballot::ranked(&[(3, &[0, 1, 2]), (2, &[1, 0, 2]), (1, &[2, 1, 0])]);
+
+        let ElectionTypeResult::CoombsResult(result) = ballot::tally(
+            ElectionType::Coombs,
+            &["Alice", "Bob", "Alice"],
+            &ballots,
+            1,
+        )
+        .unwrap() else {
+            panic!("Coombs gave another method's result");
+        };
+
+        assert_eq!(result.rounds[1].eliminated_candidates, BTreeSet::from([2]));
+    }
+}
@@ -0,0 +1,120 @@
+use serde::Serialize;
+
+use super::{
+    pairwise::PairwiseMatrix,
+    voting_methods::{fill, vote_bundle, MandatoryPreferentialBallot, OptionRating, VoteBundle},
+    Candidate, ElectionImpl, ElectionTypeResult,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CopelandScore {
+    pub option_index: usize,
+    pub wins: usize,
+    pub ties: usize,
+    pub losses: usize,
+}
+
+impl CopelandScore {
+    // A head to head win is worth a point and a tie half a point
+    pub fn score(&self) -> f64 {
+        self.wins as f64 + self.ties as f64 / 2.0
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CopelandResult<C> {
+    pub winner: C,
+    pub total_votes: usize,
+    pub matrix: PairwiseMatrix,
+    pub scores: Vec<CopelandScore>,
+    pub bundles: Vec<VoteBundle<MandatoryPreferentialBallot>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Copeland;
+
+impl ElectionImpl for Copeland {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+    ) -> ElectionTypeResult<C> {
+        let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+
+        let bundles = vote_bundle(&votes);
+
+        let matrix = PairwiseMatrix::new(options.len(), &bundles);
+
+        let mut scores = vec![];
+        for option_index in 0..options.len() {
+            let mut score = CopelandScore {
+                option_index,
+                wins: 0,
+                ties: 0,
+                losses: 0,
+            };
+
+            for other in 0..options.len() {
+                if other == option_index {
+                    continue;
+                }
+
+                match matrix.margin(option_index, other) {
+                    margin if margin > 0 => score.wins += 1,
+                    margin if margin < 0 => score.losses += 1,
+                    _ => score.ties += 1,
+                }
+            }
+
+            scores.push(score);
+        }
+
+        scores.sort_by(|a, b| b.score().partial_cmp(&a.score()).unwrap());
+
+        ElectionTypeResult::CopelandResult(CopelandResult {
+            winner: options[scores[0].option_index].clone(),
+            total_votes: votes.len(),
+            matrix,
+            scores,
+            bundles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{
+        ballot,
+        tests::{condorcet_cycle, winner, CANDIDATES},
+        ElectionType, ElectionTypeResult,
+    };
+
+    #[test]
+    fn condorcet_cycle_ties_everyone() {
+        let ElectionTypeResult::CopelandResult(result) = ballot::tally(
+            ElectionType::Copeland,
+            &CANDIDATES[..3],
+            &condorcet_cycle(),
+            1,
+        )
+        .unwrap() else {
+            panic!("Copeland gave another method's result");
+        };
+
+        for score in &result.scores {
+            assert_eq!((score.wins, score.ties, score.losses), (1, 0, 1));
+        }
+    }
+
+    #[test]
+    fn counts_wins_however_heavy_the_losses() {
+        // Dave wins three head to heads but loses 2-7 to Bob, who never loses by more than a vote
+        let ballots = ballot::ranked(&[
+            (4, &[1, 3, 4, 2, 0]),
+            (2, &[3, 2, 0, 4, 1]),
+            (3, &[0, 4, 1, 3, 2]),
+        ]);
+
+        assert_eq!(winner(ElectionType::Copeland, 5, &ballots), "Dave");
+        assert_eq!(winner(ElectionType::Minimax, 5, &ballots), "Bob");
+    }
+}
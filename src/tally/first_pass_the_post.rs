@@ -1,17 +1,19 @@
+use serde::Serialize;
+
 use super::{
-    election::{ElectionImpl, ElectionOption, ElectionTypeResult},
     voting_methods::{fill, vote_bundle, OptionRating, SingleOptionBallot, VoteBundle},
+    Candidate, ElectionImpl, ElectionTypeResult,
 };
 
-#[derive(Debug)]
-pub struct FirstPastThePostTally {
-    pub option: ElectionOption,
+#[derive(Debug, Serialize)]
+pub struct FirstPastThePostTally<C> {
+    pub option: C,
     pub votes: usize,
 }
 
-#[derive(Debug, Clone)]
-pub struct FirstPastThePostResult {
-    pub winner: ElectionOption,
+#[derive(Debug, Clone, Serialize)]
+pub struct FirstPastThePostResult<C> {
+    pub winner: C,
     pub total_votes: usize,
     pub vote_bundle: Vec<VoteBundle<SingleOptionBallot>>,
 }
@@ -20,10 +22,10 @@ pub struct FirstPastThePostResult {
 pub struct FirstPastThePost;
 
 impl ElectionImpl for FirstPastThePost {
-    fn result(
-        options: &[ElectionOption],
+    fn result<C: Candidate>(
+        options: &[C],
         option_ratings: &Vec<&Vec<OptionRating>>,
-    ) -> ElectionTypeResult {
+    ) -> ElectionTypeResult<C> {
         let votes = fill::<SingleOptionBallot>(option_ratings);
 
         let bundles = vote_bundle(&votes);
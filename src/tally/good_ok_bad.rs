@@ -1,9 +1,11 @@
+use serde::Serialize;
+
 use super::{
-    election::{ElectionImpl, ElectionOption, ElectionTypeResult},
     voting_methods::{fill, vote_bundle, GoodBadOkBallot, GoodOkBad, OptionRating, VoteBundle},
+    Candidate, ElectionImpl, ElectionTypeResult,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GoodOkBadTally {
     option_index: usize,
     good: usize,
@@ -11,21 +13,21 @@ pub struct GoodOkBadTally {
     bad: usize,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RunoffTally {
     option_index: usize,
     votes: usize,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Runoff {
     pub a: RunoffTally,
     pub b: RunoffTally,
 }
 
-#[derive(Debug, Clone)]
-pub struct GoodOkBadResult {
-    pub winner: ElectionOption,
+#[derive(Debug, Clone, Serialize)]
+pub struct GoodOkBadResult<C> {
+    pub winner: C,
     pub total_votes: usize,
     pub tally: Vec<GoodOkBadTally>,
     pub runoff: Runoff,
@@ -36,10 +38,10 @@ pub struct GoodOkBadResult {
 pub struct GoodOkBadElection;
 
 impl ElectionImpl for GoodOkBadElection {
-    fn result(
-        options: &[ElectionOption],
+    fn result<C: Candidate>(
+        options: &[C],
         option_ratings: &Vec<&Vec<OptionRating>>,
-    ) -> ElectionTypeResult {
+    ) -> ElectionTypeResult<C> {
         let votes = fill::<GoodBadOkBallot>(option_ratings);
 
         let bundles = vote_bundle(&votes);
@@ -0,0 +1,378 @@
+use std::{
+    fmt,
+    io::{self, Read},
+};
+
+use super::ballot::Ballot;
+
+/// Ballots read from a file along with who was standing.
+#[derive(Debug, Clone)]
+pub struct ImportedElection {
+    pub title: Option<String>,
+    pub candidates: Vec<String>,
+    pub seats: usize,
+    pub ballots: Vec<Ballot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    // The ballot format STV counts are usually published in
+    Blt,
+    // A column per candidate holding the rank they were given, blank when unranked
+    RankedCsv,
+    // A column per candidate holding 1 when approved and 0 or blank otherwise
+    ApprovalCsv,
+    // A column per candidate holding the score they were given, blank counts as 0
+    ScoreCsv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "blt" => Some(Format::Blt),
+            "ranked" => Some(Format::RankedCsv),
+            "approval" => Some(Format::ApprovalCsv),
+            "score" => Some(Format::ScoreCsv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ImportError {
+    // 1 based, 0 when it isn't about any one line
+    pub line: usize,
+    pub message: String,
+}
+
+impl ImportError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "line {}: {}", self.line, self.message)
+        }
+    }
+}
+
+impl From<csv::Error> for ImportError {
+    fn from(err: csv::Error) -> Self {
+        let line = err.position().map(|position| position.line()).unwrap_or(0);
+        Self::new(line as usize, err.to_string())
+    }
+}
+
+/// Reads `reader` as `format`, `seats` is taken from the file when the format has it.
+/// `max_score` is what score ballots are out of and only score files need it.
+pub fn read(
+    reader: impl io::Read,
+    format: Format,
+    seats: usize,
+    max_score: Option<u32>,
+) -> Result<ImportedElection, ImportError> {
+    match format {
+        Format::Blt => {
+            let mut bytes = vec![];
+            io::BufReader::new(reader)
+                .read_to_end(&mut bytes)
+                .map_err(|err| ImportError::new(0, err.to_string()))?;
+            read_blt(&String::from_utf8_lossy(&bytes))
+        }
+        Format::RankedCsv => read_csv(reader, seats, ranked_ballot),
+        Format::ApprovalCsv => read_csv(reader, seats, approval_ballot),
+        Format::ScoreCsv => {
+            let max = max_score.filter(|max| *max > 0).ok_or_else(|| {
+                ImportError::new(0, "score ballots need the highest possible score")
+            })?;
+            read_csv(reader, seats, |line, record| {
+                score_ballot(line, record, max)
+            })
+        }
+    }
+}
+
+fn tokens(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .flat_map(|(i, line)| line.split_whitespace().map(move |token| (i + 1, token)))
+}
+
+fn parse_number(line: usize, token: &str) -> Result<i64, ImportError> {
+    token
+        .parse()
+        .map_err(|_| ImportError::new(line, format!("expected a number but found \"{}\"", token)))
+}
+
+fn next_number<'a>(
+    tokens: &mut impl Iterator<Item = (usize, &'a str)>,
+    what: &str,
+) -> Result<(usize, i64), ImportError> {
+    let (line, token) = tokens
+        .next()
+        .ok_or_else(|| ImportError::new(0, format!("ran out of file looking for {}", what)))?;
+    Ok((line, parse_number(line, token)?))
+}
+
+/// Reads a BLT file, candidates marked as withdrawn are dropped from the election.
+pub fn read_blt(text: &str) -> Result<ImportedElection, ImportError> {
+    let mut tokens = tokens(text);
+
+    let (line, candidate_count) = next_number(&mut tokens, "the number of candidates")?;
+    let (_, seats) = next_number(&mut tokens, "the number of seats")?;
+    if candidate_count <= 0 || seats <= 0 {
+        return Err(ImportError::new(
+            line,
+            "there must be at least one candidate and one seat",
+        ));
+    }
+    let candidate_count = candidate_count as usize;
+
+    let mut withdrawn = vec![];
+    let mut ballots = vec![];
+    loop {
+        let (line, weight) = next_number(&mut tokens, "a ballot")?;
+        // Withdrawn candidates come first as negative numbers
+        if weight < 0 && ballots.is_empty() {
+            withdrawn.push((-weight - 1) as usize);
+            continue;
+        }
+        if weight == 0 {
+            break;
+        }
+        if weight < 0 {
+            return Err(ImportError::new(
+                line,
+                "ballots can't have a negative weight",
+            ));
+        }
+
+        let mut ranking = vec![];
+        loop {
+            let (line, candidate) = next_number(&mut tokens, "the end of a ballot")?;
+            if candidate == 0 {
+                break;
+            }
+            if candidate < 0 || candidate as usize > candidate_count {
+                return Err(ImportError::new(
+                    line,
+                    format!("there is no candidate {}", candidate),
+                ));
+            }
+            ranking.push(candidate as usize - 1);
+        }
+        for _ in 0..weight {
+            ballots.push(Ballot::Ranked(ranking.clone()));
+        }
+    }
+
+    // Names and the title are quoted strings, which may have spaces in them
+    let rest = tokens.map(|(_, token)| token).collect::<Vec<_>>().join(" ");
+    let names = rest
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    if names.len() < candidate_count {
+        return Err(ImportError::new(
+            0,
+            format!(
+                "expected {} candidate names but found {}",
+                candidate_count,
+                names.len()
+            ),
+        ));
+    }
+
+    let standing = (0..candidate_count)
+        .filter(|candidate| !withdrawn.contains(candidate))
+        .collect::<Vec<_>>();
+    let ballots = ballots
+        .into_iter()
+        .map(|ballot| match ballot {
+            Ballot::Ranked(ranking) => Ballot::Ranked(
+                ranking
+                    .into_iter()
+                    .filter_map(|candidate| standing.iter().position(|i| *i == candidate))
+                    .collect(),
+            ),
+            ballot => ballot,
+        })
+        .collect();
+
+    Ok(ImportedElection {
+        title: names.get(candidate_count).cloned(),
+        candidates: standing.iter().map(|i| names[*i].clone()).collect(),
+        seats: (seats as usize).min(standing.len()),
+        ballots,
+    })
+}
+
+// The first row names the candidates and every row after it is a ballot
+fn read_csv(
+    reader: impl io::Read,
+    seats: usize,
+    to_ballot: impl Fn(usize, &csv::StringRecord) -> Result<Ballot, ImportError>,
+) -> Result<ImportedElection, ImportError> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+    let candidates = reader
+        .headers()?
+        .iter()
+        .map(|name| name.trim().to_string())
+        .collect::<Vec<_>>();
+
+    let mut ballots = vec![];
+    for record in reader.records() {
+        let record = record?;
+        let line = record
+            .position()
+            .map(|position| position.line())
+            .unwrap_or(0) as usize;
+        ballots.push(to_ballot(line, &record)?);
+    }
+
+    Ok(ImportedElection {
+        title: None,
+        seats: seats.min(candidates.len()),
+        candidates,
+        ballots,
+    })
+}
+
+fn cells(record: &csv::StringRecord) -> impl Iterator<Item = (usize, &str)> {
+    record
+        .iter()
+        .map(|cell| cell.trim())
+        .enumerate()
+        .filter(|(_, cell)| !cell.is_empty())
+}
+
+fn ranked_ballot(line: usize, record: &csv::StringRecord) -> Result<Ballot, ImportError> {
+    let mut ranks = vec![];
+    for (candidate, cell) in cells(record) {
+        ranks.push((parse_number(line, cell)?, candidate));
+    }
+    // Equal ranks go in column order
+    ranks.sort_by_key(|(rank, _)| *rank);
+
+    Ok(Ballot::Ranked(
+        ranks.into_iter().map(|(_, candidate)| candidate).collect(),
+    ))
+}
+
+fn approval_ballot(line: usize, record: &csv::StringRecord) -> Result<Ballot, ImportError> {
+    let mut approved = vec![];
+    for (candidate, cell) in cells(record) {
+        if parse_number(line, cell)? != 0 {
+            approved.push(candidate);
+        }
+    }
+
+    Ok(Ballot::Approval(approved))
+}
+
+fn score_ballot(line: usize, record: &csv::StringRecord, max: u32) -> Result<Ballot, ImportError> {
+    let mut scores = vec![0; record.len()];
+    for (candidate, cell) in cells(record) {
+        let score = parse_number(line, cell)?;
+        if score < 0 {
+            return Err(ImportError::new(line, "scores can't be negative"));
+        }
+        if score > max as i64 {
+            return Err(ImportError::new(
+                line,
+                format!("a score of {} is above the highest score of {}", score, max),
+            ));
+        }
+        scores[candidate] = score as u32;
+    }
+
+    Ok(Ballot::Score { scores, max })
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::{read, Format};
+    use crate::tally::ballot::Ballot;
+    use crate::tally::{
+        ballot::{self, TallyError},
+        ElectionType,
+    };
+
+    #[test]
+    fn two_candidates_skip_methods_that_need_three() {
+        let election = read(
+            "Alice,Bob\n1,2\n2,1\n1,2\n".as_bytes(),
+            Format::RankedCsv,
+            1,
+            None,
+        )
+        .unwrap();
+        assert_eq!(election.candidates, vec!["Alice", "Bob"]);
+        assert_eq!(election.ballots.len(), 3);
+
+        for election_type in ElectionType::iter() {
+            let result = ballot::tally(
+                election_type,
+                &election.candidates,
+                &election.ballots,
+                election.seats,
+            );
+            match election_type {
+                ElectionType::GoodOkBad => {
+                    assert_eq!(result.unwrap_err(), TallyError::TooFewCandidates(3))
+                }
+                _ => assert!(result.is_ok(), "{} failed", election_type.to_string()),
+            }
+        }
+    }
+    #[test]
+    fn blank_rankings_leave_no_valid_ballots() {
+        let election = read("Alice,Bob\n,\n,\n".as_bytes(), Format::RankedCsv, 1, None).unwrap();
+
+        let result = ballot::tally(
+            ElectionType::FirstPastThePost,
+            &election.candidates,
+            &election.ballots,
+            election.seats,
+        );
+
+        assert_eq!(result.unwrap_err(), TallyError::NoValidBallots);
+    }
+    #[test]
+    fn scores_keep_the_scale_they_were_given_on() {
+        let election = read("Alice,Bob\n4,2\n".as_bytes(), Format::ScoreCsv, 1, Some(10)).unwrap();
+
+        assert_eq!(
+            election.ballots,
+            vec![Ballot::Score {
+                scores: vec![4, 2],
+                max: 10
+            }]
+        );
+    }
+
+    #[test]
+    fn scores_above_the_max_are_rejected() {
+        let err = read(
+            "Alice,Bob\n4,11\n".as_bytes(),
+            Format::ScoreCsv,
+            1,
+            Some(10),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.line, 2);
+    }
+}
@@ -0,0 +1,89 @@
+use serde::Serialize;
+
+use super::{
+    pairwise::PairwiseMatrix,
+    voting_methods::{fill, vote_bundle, MandatoryPreferentialBallot, OptionRating, VoteBundle},
+    Candidate, ElectionImpl, ElectionTypeResult,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorstDefeat {
+    pub option_index: usize,
+    // Zero if the option never loses head to head
+    pub margin: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MinimaxResult<C> {
+    pub winner: C,
+    pub total_votes: usize,
+    pub matrix: PairwiseMatrix,
+    pub worst_defeats: Vec<WorstDefeat>,
+    pub bundles: Vec<VoteBundle<MandatoryPreferentialBallot>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Minimax;
+
+impl ElectionImpl for Minimax {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+    ) -> ElectionTypeResult<C> {
+        let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+
+        let bundles = vote_bundle(&votes);
+
+        let matrix = PairwiseMatrix::new(options.len(), &bundles);
+
+        let mut worst_defeats = (0..options.len())
+            .map(|option_index| WorstDefeat {
+                option_index,
+                margin: (0..options.len())
+                    .filter(|other| *other != option_index)
+                    .map(|other| matrix.margin(other, option_index))
+                    .max()
+                    .unwrap_or(0)
+                    .max(0),
+            })
+            .collect::<Vec<_>>();
+
+        worst_defeats.sort_by_key(|defeat| defeat.margin);
+
+        ElectionTypeResult::MinimaxResult(MinimaxResult {
+            winner: options[worst_defeats[0].option_index].clone(),
+            total_votes: votes.len(),
+            matrix,
+            worst_defeats,
+            bundles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{ballot, tests::winner, ElectionType};
+
+    #[test]
+    fn looks_only_at_the_worst_defeat() {
+        // Dave loses twice but never by more than 3, Carol beats three rivals and loses 8-13 to Bob
+        let ballots = ballot::ranked(&[
+            (3, &[3, 2, 4, 0, 1]),
+            (4, &[2, 0, 4, 1, 3]),
+            (5, &[1, 2, 4, 3, 0]),
+            (3, &[3, 0, 1, 2, 4]),
+            (3, &[0, 3, 4, 1, 2]),
+            (2, &[1, 2, 0, 3, 4]),
+            (1, &[4, 0, 2, 1, 3]),
+        ]);
+
+        assert_eq!(winner(ElectionType::Minimax, 5, &ballots), "Dave");
+        for election_type in [
+            ElectionType::Schulze,
+            ElectionType::RankedPairs,
+            ElectionType::Copeland,
+        ] {
+            assert_eq!(winner(election_type, 5, &ballots), "Carol");
+        }
+    }
+}
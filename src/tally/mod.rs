@@ -0,0 +1,311 @@
+pub mod anti_plurality;
+pub mod approval;
+pub mod baldwin;
+pub mod ballot;
+pub mod borda;
+pub mod coombs;
+pub mod copeland;
+pub mod first_pass_the_post;
+pub mod good_ok_bad;
+pub mod import;
+pub mod minimax;
+pub mod nanson;
+pub mod pairwise;
+pub mod preferential;
+pub mod proportional_approval;
+pub mod ranked_pairs;
+pub mod schulze;
+pub mod single_transferable_vote;
+pub mod star;
+pub mod usual_judgment;
+pub mod voting_methods;
+
+use std::fmt::Debug;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use self::{
+    anti_plurality::AntiPluralityResult,
+    approval::{Approval, ApprovalResult},
+    baldwin::{Baldwin, BaldwinResult},
+    borda::{BordaPoints, BordaResult},
+    coombs::{Coombs, CoombsResult},
+    copeland::{Copeland, CopelandResult},
+    first_pass_the_post::{FirstPastThePost, FirstPastThePostResult},
+    good_ok_bad::{GoodOkBadElection, GoodOkBadResult},
+    minimax::{Minimax, MinimaxResult},
+    nanson::{Nanson, NansonResult},
+    preferential::{Preferential, PreferentialResult},
+    proportional_approval::{ProportionalApproval, ProportionalApprovalResult},
+    ranked_pairs::{RankedPairs, RankedPairsResult},
+    schulze::{Schulze, SchulzeResult},
+    single_transferable_vote::{SingleTransferableVote, SingleTransferableVoteResult},
+    star::{Star, StarResult},
+    usual_judgment::UsualJudgmentResult,
+    voting_methods::OptionRating,
+};
+
+/// Anything that can stand in an election, the tallies only ever clone and compare them.
+pub trait Candidate: Clone + PartialEq + Debug + Serialize {}
+
+impl<T> Candidate for T where T: Clone + PartialEq + Debug + Serialize {}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum ElectionTypeResult<C> {
+    FirstPastThePostResult(FirstPastThePostResult<C>),
+    ApprovalResult(ApprovalResult<C>),
+    PreferentialResult(PreferentialResult<C>),
+    GoodOkBadResult(GoodOkBadResult<C>),
+    StarResult(StarResult<C>),
+    AntiPluralityResult(AntiPluralityResult<C>),
+    UsualJudgment(UsualJudgmentResult<C>),
+    SchulzeResult(SchulzeResult<C>),
+    RankedPairsResult(RankedPairsResult<C>),
+    CopelandResult(CopelandResult<C>),
+    MinimaxResult(MinimaxResult<C>),
+    BordaResult(BordaResult<C>),
+    NansonResult(NansonResult<C>),
+    BaldwinResult(BaldwinResult<C>),
+    CoombsResult(CoombsResult<C>),
+    SingleTransferableVoteResult(SingleTransferableVoteResult<C>),
+    ProportionalApprovalResult(ProportionalApprovalResult<C>),
+}
+
+impl<C> ElectionTypeResult<C> {
+    pub fn get_winner(&self) -> &C {
+        match self {
+            ElectionTypeResult::FirstPastThePostResult(result) => &result.winner,
+            ElectionTypeResult::ApprovalResult(result) => &result.winner,
+            ElectionTypeResult::PreferentialResult(result) => &result.winner,
+            ElectionTypeResult::GoodOkBadResult(result) => &result.winner,
+            ElectionTypeResult::StarResult(result) => &result.winner,
+            ElectionTypeResult::AntiPluralityResult(result) => &result.winner,
+            ElectionTypeResult::UsualJudgment(result) => &result.winner,
+            ElectionTypeResult::SchulzeResult(result) => &result.winner,
+            ElectionTypeResult::RankedPairsResult(result) => &result.winner,
+            ElectionTypeResult::CopelandResult(result) => &result.winner,
+            ElectionTypeResult::MinimaxResult(result) => &result.winner,
+            ElectionTypeResult::BordaResult(result) => &result.winner,
+            ElectionTypeResult::NansonResult(result) => &result.winner,
+            ElectionTypeResult::BaldwinResult(result) => &result.winner,
+            ElectionTypeResult::CoombsResult(result) => &result.winner,
+            ElectionTypeResult::SingleTransferableVoteResult(result) => &result.winners[0],
+            ElectionTypeResult::ProportionalApprovalResult(result) => &result.winners[0],
+        }
+    }
+
+    // Every option that won a seat in the order they won it, single winner methods only have one
+    pub fn get_winners(&self) -> Vec<&C> {
+        match self {
+            ElectionTypeResult::SingleTransferableVoteResult(result) => {
+                result.winners.iter().collect()
+            }
+            ElectionTypeResult::ProportionalApprovalResult(result) => {
+                result.winners.iter().collect()
+            }
+            _ => vec![self.get_winner()],
+        }
+    }
+
+    pub fn winners_to_string(&self) -> String
+    where
+        C: ToString,
+    {
+        self.get_winners()
+            .iter()
+            .map(|winner| winner.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn get_type(&self) -> ElectionType {
+        match self {
+            ElectionTypeResult::FirstPastThePostResult(_) => ElectionType::FirstPastThePost,
+            ElectionTypeResult::ApprovalResult(_) => ElectionType::Approval,
+            ElectionTypeResult::PreferentialResult(_) => ElectionType::Preferential,
+            ElectionTypeResult::GoodOkBadResult(_) => ElectionType::GoodOkBad,
+            ElectionTypeResult::StarResult(_) => ElectionType::Star,
+            ElectionTypeResult::AntiPluralityResult(_) => ElectionType::AntiPlurality,
+            ElectionTypeResult::UsualJudgment(_) => ElectionType::UsualJudgment,
+            ElectionTypeResult::SchulzeResult(_) => ElectionType::Schulze,
+            ElectionTypeResult::RankedPairsResult(_) => ElectionType::RankedPairs,
+            ElectionTypeResult::CopelandResult(_) => ElectionType::Copeland,
+            ElectionTypeResult::MinimaxResult(_) => ElectionType::Minimax,
+            ElectionTypeResult::BordaResult(result) => result.points.election_type(),
+            ElectionTypeResult::NansonResult(_) => ElectionType::Nanson,
+            ElectionTypeResult::BaldwinResult(_) => ElectionType::Baldwin,
+            ElectionTypeResult::CoombsResult(_) => ElectionType::Coombs,
+            ElectionTypeResult::SingleTransferableVoteResult(_) => {
+                ElectionType::SingleTransferableVote
+            }
+            ElectionTypeResult::ProportionalApprovalResult(_) => ElectionType::ProportionalApproval,
+        }
+    }
+}
+
+pub trait ElectionImpl {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+    ) -> ElectionTypeResult<C>;
+}
+
+pub trait MultiWinnerElectionImpl {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+        seats: usize,
+    ) -> ElectionTypeResult<C>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash, EnumIter, Serialize, Deserialize)]
+pub enum ElectionType {
+    FirstPastThePost,
+    Approval,
+    Preferential,
+    GoodOkBad,
+    Star,
+    AntiPlurality,
+    UsualJudgment,
+    Schulze,
+    RankedPairs,
+    Copeland,
+    Minimax,
+    Borda,
+    Dowdall,
+    Nanson,
+    Baldwin,
+    Coombs,
+    SingleTransferableVote,
+    ProportionalApproval,
+}
+
+impl ElectionType {
+    pub fn result<C: Candidate>(
+        &self,
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+        seats: usize,
+    ) -> ElectionTypeResult<C> {
+        match self {
+            ElectionType::FirstPastThePost => FirstPastThePost::result(options, option_ratings),
+            ElectionType::Approval => Approval::result(options, option_ratings),
+            ElectionType::Preferential => Preferential::result(options, option_ratings),
+            ElectionType::GoodOkBad => GoodOkBadElection::result(options, option_ratings),
+            ElectionType::Star => Star::result(options, option_ratings),
+            ElectionType::AntiPlurality => anti_plurality::result(options, option_ratings),
+            ElectionType::UsualJudgment => usual_judgment::result(options, option_ratings),
+            ElectionType::Schulze => Schulze::result(options, option_ratings),
+            ElectionType::RankedPairs => RankedPairs::result(options, option_ratings),
+            ElectionType::Copeland => Copeland::result(options, option_ratings),
+            ElectionType::Minimax => Minimax::result(options, option_ratings),
+            ElectionType::Borda => borda::result(options, option_ratings, BordaPoints::Standard),
+            ElectionType::Dowdall => borda::result(options, option_ratings, BordaPoints::Dowdall),
+            ElectionType::Nanson => Nanson::result(options, option_ratings),
+            ElectionType::Baldwin => Baldwin::result(options, option_ratings),
+            ElectionType::Coombs => Coombs::result(options, option_ratings),
+            ElectionType::SingleTransferableVote => {
+                SingleTransferableVote::result(options, option_ratings, seats)
+            }
+            ElectionType::ProportionalApproval => {
+                ProportionalApproval::result(options, option_ratings, seats)
+            }
+        }
+    }
+
+    /// The fewest options the method can count.
+    pub fn min_options(&self) -> usize {
+        match self {
+            // Sorts the options into its top three
+            ElectionType::GoodOkBad => 3,
+            // Runs off the top two scores
+            ElectionType::Star => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl ToString for ElectionType {
+    fn to_string(&self) -> String {
+        match self {
+            ElectionType::FirstPastThePost => "First Past The Post".to_string(),
+            ElectionType::Approval => "Approval".to_string(),
+            ElectionType::Preferential => "Preferential".to_string(),
+            ElectionType::GoodOkBad => "Good Ok Bad".to_string(),
+            ElectionType::Star => "Star".to_string(),
+            ElectionType::AntiPlurality => "Anti Plurality".to_string(),
+            ElectionType::UsualJudgment => "Usual Judgment".to_string(),
+            ElectionType::Schulze => "Schulze".to_string(),
+            ElectionType::RankedPairs => "Ranked Pairs".to_string(),
+            ElectionType::Copeland => "Copeland".to_string(),
+            ElectionType::Minimax => "Minimax".to_string(),
+            ElectionType::Borda => "Borda Count".to_string(),
+            ElectionType::Dowdall => "Dowdall".to_string(),
+            ElectionType::Nanson => "Nanson".to_string(),
+            ElectionType::Baldwin => "Baldwin".to_string(),
+            ElectionType::Coombs => "Coombs".to_string(),
+            ElectionType::SingleTransferableVote => "Single Transferable Vote".to_string(),
+            ElectionType::ProportionalApproval => "Proportional Approval".to_string(),
+        }
+    }
+}
+
+pub mod want_level {
+    pub const EXTREMELY_NEGATIVE: i32 = -30;
+    pub const NEGATIVE: i32 = -20;
+    pub const SLIGHTLY_NEGATIVE: i32 = -10;
+    pub const NEUTRAL: i32 = 0;
+    pub const SLIGHTLY_POSITIVE: i32 = 10;
+    pub const POSITIVE: i32 = 20;
+    pub const EXTREMELY_POSITIVE: i32 = 30;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{
+        ballot::{self, Ballot},
+        ElectionType,
+    };
+
+    pub const CANDIDATES: [&str; 5] = ["Alice", "Bob", "Carol", "Dave", "Erin"];
+
+    /// Carol beats Alice 6-3, Alice beats Bob 7-2 and Bob beats Carol 5-4, so no one beats everyone.
+    pub fn condorcet_cycle() -> Vec<Ballot> {
+        ballot::ranked(&[(4, &[2, 0, 1]), (3, &[0, 1, 2]), (2, &[1, 2, 0])])
+    }
+
+    /// Alice beats both rivals head to head but Bob is second on every ballot that doesn't put him first.
+    pub fn compromise_beats_condorcet_winner() -> Vec<Ballot> {
+        ballot::ranked(&[(3, &[0, 1, 2]), (2, &[1, 2, 0])])
+    }
+
+    /// Four candidates where Alice gets 9 Borda points, Bob 12, Carol 11 and Dave 10.
+    pub fn borda_split() -> Vec<Ballot> {
+        ballot::ranked(&[
+            (2, &[3, 2, 1, 0]),
+            (2, &[2, 1, 0, 3]),
+            (1, &[0, 3, 2, 1]),
+            (2, &[1, 0, 3, 2]),
+        ])
+    }
+
+    /// Who a single winner method elects out of as many of `CANDIDATES` as there are.
+    pub fn winner(election_type: ElectionType, candidate_count: usize, ballots: &[Ballot]) -> &str {
+        ballot::tally(election_type, &CANDIDATES[..candidate_count], ballots, 1)
+            .unwrap()
+            .get_winner()
+    }
+
+    #[test]
+    fn condorcet_cycle_goes_to_the_narrowest_defeat() {
+        // Carol only loses by 1 vote, Alice by 3 and Bob by 5
+        for election_type in [
+            ElectionType::Schulze,
+            ElectionType::RankedPairs,
+            ElectionType::Minimax,
+        ] {
+            assert_eq!(winner(election_type, 3, &condorcet_cycle()), "Carol");
+        }
+    }
+}
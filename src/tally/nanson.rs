@@ -0,0 +1,107 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
+use super::{
+    borda::{self, BordaPoints},
+    preferential::Round,
+    voting_methods::{
+        fill, vote_bundle, MandatoryPreferentialBallot, OptionRating, VoteBundle, VoteCount,
+    },
+    Candidate, ElectionImpl, ElectionTypeResult,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NansonResult<C> {
+    pub winner: C,
+    pub total_votes: usize,
+    pub rounds: Vec<Round<C>>,
+    pub bundles: Vec<VoteBundle<MandatoryPreferentialBallot>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Nanson;
+
+impl ElectionImpl for Nanson {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+    ) -> ElectionTypeResult<C> {
+        let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+
+        let bundles = vote_bundle(&votes);
+
+        let mut rounds = Vec::new();
+        let mut eliminated_candidates = BTreeSet::new();
+        loop {
+            let score_tally = borda::tally(
+                options.len(),
+                &bundles,
+                &eliminated_candidates,
+                &BordaPoints::Standard,
+            );
+
+            let average =
+                score_tally.iter().map(|tally| tally.score).sum::<f64>() / score_tally.len() as f64;
+
+            // Everything at or below the average borda score goes in one round
+            let below_average = score_tally
+                .iter()
+                .filter(|tally| tally.score <= average)
+                .map(|tally| tally.option_index)
+                .collect::<Vec<_>>();
+
+            rounds.push(Round {
+                eliminated_candidates: eliminated_candidates.clone(),
+                vote_tally: score_tally
+                    .into_iter()
+                    .map(|tally| VoteCount {
+                        option: options[tally.option_index].clone(),
+                        votes: tally.score as i64,
+                    })
+                    .collect(),
+            });
+
+            // Either one option is left or they are all tied on the average
+            if below_average.len() == options.len() - eliminated_candidates.len() {
+                break;
+            }
+
+            eliminated_candidates.extend(below_average);
+        }
+
+        ElectionTypeResult::NansonResult(NansonResult {
+            winner: rounds.last().unwrap().vote_tally[0].option.clone(),
+            total_votes: votes.len(),
+            rounds,
+            bundles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{
+        tests::{borda_split, compromise_beats_condorcet_winner, winner},
+        ElectionType,
+    };
+
+    #[test]
+    fn finds_the_condorcet_winner_borda_misses() {
+        // Carol's 2 points are the only ones under the average of 5, then Alice beats Bob 3-2
+        assert_eq!(
+            winner(
+                ElectionType::Nanson,
+                3,
+                &compromise_beats_condorcet_winner()
+            ),
+            "Alice"
+        );
+    }
+
+    #[test]
+    fn drops_everyone_below_average_at_once() {
+        // Alice and Dave both fall under the average of 10.5, then Carol beats Bob 5-2
+        assert_eq!(winner(ElectionType::Nanson, 4, &borda_split()), "Carol");
+    }
+}
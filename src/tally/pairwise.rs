@@ -0,0 +1,78 @@
+use serde::Serialize;
+
+use super::voting_methods::{MandatoryPreferentialBallot, VoteBundle};
+
+/// Head to head tallies between every pair of options.
+/// `preferences[a][b]` is how many voters ranked option `a` above option `b`.
+/// Options left off a ballot lose to everything on it and tie with each other.
+#[derive(Debug, Clone, Serialize)]
+pub struct PairwiseMatrix {
+    pub preferences: Vec<Vec<usize>>,
+}
+
+impl PairwiseMatrix {
+    pub fn new(option_count: usize, bundles: &[VoteBundle<MandatoryPreferentialBallot>]) -> Self {
+        let mut preferences = vec![vec![0; option_count]; option_count];
+
+        for bundle in bundles {
+            let ranking = &bundle.ballot.votes;
+            let mut unranked = vec![true; option_count];
+            for option_index in ranking {
+                unranked[*option_index] = false;
+            }
+
+            for (i, preferred) in ranking.iter().enumerate() {
+                let below = ranking[i + 1..]
+                    .iter()
+                    .copied()
+                    .chain((0..option_count).filter(|option_index| unranked[*option_index]));
+                for other in below {
+                    preferences[*preferred][other] += bundle.votes;
+                }
+            }
+        }
+
+        Self { preferences }
+    }
+
+    pub fn option_count(&self) -> usize {
+        self.preferences.len()
+    }
+
+    pub fn prefer(&self, a: usize, b: usize) -> usize {
+        self.preferences[a][b]
+    }
+
+    pub fn margin(&self, a: usize, b: usize) -> i64 {
+        self.prefer(a, b) as i64 - self.prefer(b, a) as i64
+    }
+
+    pub fn beats(&self, a: usize, b: usize) -> bool {
+        self.prefer(a, b) > self.prefer(b, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{ballot, ElectionType, ElectionTypeResult};
+
+    #[test]
+    fn unranked_options_lose_to_ranked_and_tie_each_other() {
+        let ballots = ballot::ranked(&[(1, &[2])]);
+
+        let ElectionTypeResult::CopelandResult(result) = ballot::tally(
+            ElectionType::Copeland,
+            &["Alice", "Bob", "Carol"],
+            &ballots,
+            1,
+        )
+        .unwrap() else {
+            panic!("Copeland gave another method's result");
+        };
+
+        assert_eq!(result.matrix.prefer(2, 0), 1);
+        assert_eq!(result.matrix.prefer(2, 1), 1);
+        assert_eq!(result.matrix.prefer(0, 1), 0);
+        assert_eq!(result.matrix.prefer(1, 0), 0);
+    }
+}
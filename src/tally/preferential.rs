@@ -0,0 +1,121 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use super::{
+    voting_methods::{
+        fill, vote_bundle, MandatoryPreferentialBallot, OptionRating, VoteBundle, VoteCount,
+    },
+    Candidate, ElectionImpl, ElectionTypeResult,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Round<C> {
+    pub eliminated_candidates: BTreeSet<usize>,
+    pub vote_tally: Vec<VoteCount<C>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PreferentialResult<C> {
+    pub winner: C,
+    pub total_votes: usize,
+    pub rounds: Vec<Round<C>>,
+    pub bundles: Vec<VoteBundle<MandatoryPreferentialBallot>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Preferential;
+
+impl ElectionImpl for Preferential {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+    ) -> ElectionTypeResult<C> {
+        let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+
+        let bundles = vote_bundle(&votes);
+
+        let mut rounds = Vec::new();
+        let mut eliminated_candidates = BTreeSet::new();
+        loop {
+            let mut vote_tally = BTreeMap::<usize, i64>::new();
+            for i in 0..options.len() {
+                vote_tally.insert(i, 0);
+            }
+
+            for bundle in &bundles {
+                for option_index in &bundle.ballot.votes {
+                    if !eliminated_candidates.contains(option_index) {
+                        *vote_tally.get_mut(option_index).unwrap() += bundle.votes as i64;
+                        break;
+                    }
+                }
+            }
+
+            let mut standing = vote_tally
+                .into_iter()
+                .filter(|(k, _)| !eliminated_candidates.contains(k))
+                .collect::<Vec<_>>();
+
+            standing.sort_by(|a, b| b.1.cmp(&a.1));
+
+            // Ballots with no one left on them have run out and no longer count towards a majority
+            let continuing = standing.iter().map(|(_, votes)| votes).sum::<i64>();
+            let (_, top_votes) = standing[0];
+            let (bottom, _) = *standing.last().unwrap();
+
+            rounds.push(Round {
+                eliminated_candidates: eliminated_candidates.clone(),
+                vote_tally: standing
+                    .into_iter()
+                    .map(|(k, v)| VoteCount {
+                        option: options[k].clone(),
+                        votes: v,
+                    })
+                    .collect(),
+            });
+
+            if top_votes * 2 > continuing {
+                break;
+            }
+
+            if eliminated_candidates.len() == options.len() - 1 {
+                break;
+            }
+
+            eliminated_candidates.insert(bottom);
+        }
+
+        ElectionTypeResult::PreferentialResult(PreferentialResult {
+            winner: rounds.last().unwrap().vote_tally[0].option.clone(),
+            total_votes: votes.len(),
+            rounds,
+            bundles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::tally::{ballot, ElectionType, ElectionTypeResult};
+
+    #[test]
+    fn eliminates_the_candidate_not_their_namesake() {
+        let ballots = ballot::ranked(&[(3, &[0]), (2, &[1]), (1, &[2])]);
+
+        let ElectionTypeResult::PreferentialResult(result) = ballot::tally(
+            ElectionType::Preferential,
+            &["Alice", "Bob", "Alice"],
+            &ballots,
+            1,
+        )
+        .unwrap() else {
+            panic!("Preferential gave another method's result");
+        };
+
+        assert_eq!(result.rounds[1].eliminated_candidates, BTreeSet::from([2]));
+        assert_eq!(result.winner, "Alice");
+    }
+}
@@ -0,0 +1,114 @@
+use serde::Serialize;
+
+use super::{
+    voting_methods::{fill, vote_bundle, MultipleOptionBallot, OptionRating, VoteBundle},
+    Candidate, ElectionTypeResult, MultiWinnerElectionImpl,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProportionalApprovalTally {
+    pub option_index: usize,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProportionalApprovalRound {
+    pub elected: Vec<usize>,
+    pub score_tally: Vec<ProportionalApprovalTally>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProportionalApprovalResult<C> {
+    // In the order they were elected
+    pub winners: Vec<C>,
+    pub seats: usize,
+    pub total_votes: usize,
+    pub rounds: Vec<ProportionalApprovalRound>,
+    pub bundles: Vec<VoteBundle<MultipleOptionBallot>>,
+}
+
+// Sequential proportional approval voting
+#[derive(Debug, Clone, Default)]
+pub struct ProportionalApproval;
+
+impl MultiWinnerElectionImpl for ProportionalApproval {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+        seats: usize,
+    ) -> ElectionTypeResult<C> {
+        let votes = fill::<MultipleOptionBallot>(option_ratings);
+
+        let seats = seats.min(options.len());
+        let bundles = vote_bundle(&votes);
+
+        let mut rounds = Vec::new();
+        let mut elected: Vec<usize> = Vec::new();
+        while elected.len() < seats {
+            let mut score_tally = (0..options.len())
+                .filter(|option_index| !elected.contains(option_index))
+                .map(|option_index| ProportionalApprovalTally {
+                    option_index,
+                    score: 0.0,
+                })
+                .collect::<Vec<_>>();
+
+            for bundle in &bundles {
+                // A ballot counts for less the more of its picks already won
+                let already_elected = bundle
+                    .ballot
+                    .voted_for
+                    .iter()
+                    .filter(|option_index| elected.contains(option_index))
+                    .count();
+                let weight = bundle.votes as f64 / (already_elected + 1) as f64;
+
+                for tally in &mut score_tally {
+                    if bundle.ballot.voted_for.contains(&tally.option_index) {
+                        tally.score += weight;
+                    }
+                }
+            }
+
+            score_tally.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+
+            let top = score_tally[0].option_index;
+
+            rounds.push(ProportionalApprovalRound {
+                elected: elected.clone(),
+                score_tally,
+            });
+
+            elected.push(top);
+        }
+
+        ElectionTypeResult::ProportionalApprovalResult(ProportionalApprovalResult {
+            winners: elected.iter().map(|i| options[*i].clone()).collect(),
+            seats,
+            total_votes: votes.len(),
+            rounds,
+            bundles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{ballot, tests::CANDIDATES, ElectionType};
+
+    #[test]
+    fn discounts_ballots_that_already_won_a_seat() {
+        // Approval would seat Alice and Bob, but Bob's backers already have Alice
+        let ballots = ballot::approved(&[(6, &[0, 1]), (1, &[0]), (5, &[2])]);
+
+        let result = ballot::tally(
+            ElectionType::ProportionalApproval,
+            &CANDIDATES[..3],
+            &ballots,
+            2,
+        )
+        .unwrap();
+
+        assert_eq!(result.get_winners(), vec![&"Alice", &"Carol"]);
+    }
+}
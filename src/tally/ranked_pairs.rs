@@ -0,0 +1,139 @@
+use serde::Serialize;
+
+use super::{
+    pairwise::PairwiseMatrix,
+    voting_methods::{fill, vote_bundle, MandatoryPreferentialBallot, OptionRating, VoteBundle},
+    Candidate, ElectionImpl, ElectionTypeResult,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedPair {
+    pub winner: usize,
+    pub loser: usize,
+    pub winning_votes: usize,
+    pub losing_votes: usize,
+    // False when locking the pair in would have made a cycle
+    pub locked: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedPairsResult<C> {
+    pub winner: C,
+    pub total_votes: usize,
+    pub matrix: PairwiseMatrix,
+    pub pairs: Vec<RankedPair>,
+    pub bundles: Vec<VoteBundle<MandatoryPreferentialBallot>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RankedPairs;
+
+fn has_path(locked: &[Vec<bool>], from: usize, to: usize) -> bool {
+    let mut visited = vec![false; locked.len()];
+    let mut to_visit = vec![from];
+
+    while let Some(current) = to_visit.pop() {
+        if current == to {
+            return true;
+        }
+
+        if visited[current] {
+            continue;
+        }
+        visited[current] = true;
+
+        for (next, is_locked) in locked[current].iter().enumerate() {
+            if *is_locked {
+                to_visit.push(next);
+            }
+        }
+    }
+
+    false
+}
+
+impl ElectionImpl for RankedPairs {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+    ) -> ElectionTypeResult<C> {
+        let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+
+        let bundles = vote_bundle(&votes);
+
+        let matrix = PairwiseMatrix::new(options.len(), &bundles);
+
+        let mut pairs = vec![];
+        for a in 0..options.len() {
+            for b in 0..options.len() {
+                if a != b && matrix.beats(a, b) {
+                    pairs.push(RankedPair {
+                        winner: a,
+                        loser: b,
+                        winning_votes: matrix.prefer(a, b),
+                        losing_votes: matrix.prefer(b, a),
+                        locked: false,
+                    });
+                }
+            }
+        }
+
+        // Strongest majorities first, the smaller opposition breaks ties
+        pairs.sort_by(|a, b| {
+            b.winning_votes
+                .cmp(&a.winning_votes)
+                .then(a.losing_votes.cmp(&b.losing_votes))
+        });
+
+        let mut locked = vec![vec![false; options.len()]; options.len()];
+        for pair in &mut pairs {
+            if has_path(&locked, pair.loser, pair.winner) {
+                continue;
+            }
+
+            locked[pair.winner][pair.loser] = true;
+            pair.locked = true;
+        }
+
+        // The winner is the source of the locked graph, nothing is locked in over it
+        let winner = (0..options.len())
+            .find(|option| !(0..options.len()).any(|other| locked[other][*option]))
+            .unwrap();
+
+        ElectionTypeResult::RankedPairsResult(RankedPairsResult {
+            winner: options[winner].clone(),
+            total_votes: votes.len(),
+            matrix,
+            pairs,
+            bundles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{ballot, tests::winner, ElectionType};
+
+    #[test]
+    fn parts_ways_with_the_other_condorcet_methods() {
+        // Erin wins the most head to heads and only loses to Alice by a vote, but Dave's 9-6 win
+        // over Alice would close a cycle through Carol so nothing is locked in over Alice
+        let ballots = ballot::ranked(&[
+            (4, &[0, 2, 4, 3, 1]),
+            (2, &[1, 0, 2, 4, 3]),
+            (2, &[3, 0, 1, 4, 2]),
+            (1, &[4, 3, 0, 1, 2]),
+            (4, &[4, 2, 3, 1, 0]),
+            (2, &[1, 4, 3, 0, 2]),
+        ]);
+
+        assert_eq!(winner(ElectionType::RankedPairs, 5, &ballots), "Alice");
+        for election_type in [
+            ElectionType::Schulze,
+            ElectionType::Copeland,
+            ElectionType::Minimax,
+        ] {
+            assert_eq!(winner(election_type, 5, &ballots), "Erin");
+        }
+    }
+}
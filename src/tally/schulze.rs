@@ -0,0 +1,102 @@
+use serde::Serialize;
+
+use super::{
+    pairwise::PairwiseMatrix,
+    voting_methods::{fill, vote_bundle, MandatoryPreferentialBallot, OptionRating, VoteBundle},
+    Candidate, ElectionImpl, ElectionTypeResult,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SchulzeResult<C> {
+    pub winner: C,
+    pub total_votes: usize,
+    pub matrix: PairwiseMatrix,
+    // strongest_paths[a][b] is the weakest link on the strongest path from a to b
+    pub strongest_paths: Vec<Vec<usize>>,
+    pub bundles: Vec<VoteBundle<MandatoryPreferentialBallot>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Schulze;
+
+fn strongest_paths(matrix: &PairwiseMatrix) -> Vec<Vec<usize>> {
+    let option_count = matrix.option_count();
+    let mut paths = vec![vec![0; option_count]; option_count];
+
+    for (a, row) in paths.iter_mut().enumerate() {
+        for (b, path) in row.iter_mut().enumerate() {
+            if a != b && matrix.beats(a, b) {
+                *path = matrix.prefer(a, b);
+            }
+        }
+    }
+
+    for i in 0..option_count {
+        for j in 0..option_count {
+            if i == j {
+                continue;
+            }
+
+            for k in 0..option_count {
+                if i == k || j == k {
+                    continue;
+                }
+
+                paths[j][k] = paths[j][k].max(paths[j][i].min(paths[i][k]));
+            }
+        }
+    }
+
+    paths
+}
+
+impl ElectionImpl for Schulze {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+    ) -> ElectionTypeResult<C> {
+        let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+
+        let bundles = vote_bundle(&votes);
+
+        let matrix = PairwiseMatrix::new(options.len(), &bundles);
+        let strongest_paths = strongest_paths(&matrix);
+
+        // There is always at least one option whose paths are never weaker than its rivals'
+        let winner = (0..options.len())
+            .find(|a| {
+                (0..options.len())
+                    .filter(|b| a != b)
+                    .all(|b| strongest_paths[*a][b] >= strongest_paths[b][*a])
+            })
+            .unwrap();
+
+        ElectionTypeResult::SchulzeResult(SchulzeResult {
+            winner: options[winner].clone(),
+            total_votes: votes.len(),
+            matrix,
+            strongest_paths,
+            bundles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{ballot, tests::winner, ElectionType};
+
+    #[test]
+    fn parts_ways_with_ranked_pairs() {
+        // Ranked Pairs drops Alice's 8-5 win over Carol since Carol beats Dave who beats Alice 9-4,
+        // Schulze lets Erin reach Carol through Alice at 8 against Carol's 7-6 win over her
+        let ballots = ballot::ranked(&[
+            (2, &[0, 2, 3, 1, 4]),
+            (4, &[4, 3, 0, 2, 1]),
+            (5, &[2, 1, 4, 3, 0]),
+            (2, &[4, 0, 2, 1, 3]),
+        ]);
+
+        assert_eq!(winner(ElectionType::Schulze, 5, &ballots), "Erin");
+        assert_eq!(winner(ElectionType::RankedPairs, 5, &ballots), "Carol");
+    }
+}
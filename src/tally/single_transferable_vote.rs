@@ -0,0 +1,213 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
+use super::{
+    voting_methods::{fill, vote_bundle, MandatoryPreferentialBallot, OptionRating, VoteBundle},
+    Candidate, ElectionTypeResult, MultiWinnerElectionImpl,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TransferableTally {
+    pub option_index: usize,
+    pub votes: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TransferableRound {
+    pub elected: Vec<usize>,
+    pub eliminated_candidates: BTreeSet<usize>,
+    pub vote_tally: Vec<TransferableTally>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SingleTransferableVoteResult<C> {
+    // In the order they were elected
+    pub winners: Vec<C>,
+    pub seats: usize,
+    pub quota: f64,
+    pub total_votes: usize,
+    pub rounds: Vec<TransferableRound>,
+    pub bundles: Vec<VoteBundle<MandatoryPreferentialBallot>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SingleTransferableVote;
+
+// Droop quota, the fewest votes only `seats` options can reach
+fn droop_quota(total_votes: usize, seats: usize) -> f64 {
+    ((total_votes / (seats + 1)) + 1) as f64
+}
+
+impl MultiWinnerElectionImpl for SingleTransferableVote {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+        seats: usize,
+    ) -> ElectionTypeResult<C> {
+        let votes = fill::<MandatoryPreferentialBallot>(option_ratings);
+
+        let seats = seats.min(options.len());
+        let quota = droop_quota(votes.len(), seats);
+        let bundles = vote_bundle(&votes);
+
+        // Every bundle starts at full value and loses some each time it helps elect someone
+        let mut weights = bundles
+            .iter()
+            .map(|bundle| bundle.votes as f64)
+            .collect::<Vec<_>>();
+
+        let mut rounds = Vec::new();
+        let mut elected: Vec<usize> = Vec::new();
+        let mut eliminated_candidates = BTreeSet::new();
+        while elected.len() < seats {
+            let is_hopeful = |option_index: &usize| {
+                !elected.contains(option_index) && !eliminated_candidates.contains(option_index)
+            };
+
+            // Who each bundle is currently counting towards
+            let assigned = bundles
+                .iter()
+                .map(|bundle| bundle.ballot.votes.iter().find(|i| is_hopeful(i)).cloned())
+                .collect::<Vec<_>>();
+
+            let mut vote_tally = (0..options.len())
+                .filter(is_hopeful)
+                .map(|option_index| TransferableTally {
+                    option_index,
+                    votes: 0.0,
+                })
+                .collect::<Vec<_>>();
+
+            for (assigned, weight) in assigned.iter().zip(&weights) {
+                if let Some(option_index) = assigned {
+                    let tally = vote_tally
+                        .iter_mut()
+                        .find(|tally| tally.option_index == *option_index)
+                        .unwrap();
+                    tally.votes += weight;
+                }
+            }
+
+            vote_tally.sort_by(|a, b| b.votes.partial_cmp(&a.votes).unwrap());
+
+            let top = vote_tally[0].clone();
+            let bottom = vote_tally.iter().last().unwrap().clone();
+            let remaining = vote_tally.len();
+
+            rounds.push(TransferableRound {
+                elected: elected.clone(),
+                eliminated_candidates: eliminated_candidates.clone(),
+                vote_tally: vote_tally.clone(),
+            });
+
+            // Only as many options are left as there are seats so they all get in
+            if elected.len() + remaining <= seats {
+                elected.extend(vote_tally.iter().map(|tally| tally.option_index));
+                break;
+            }
+
+            if top.votes >= quota {
+                // Gregory method, every ballot for the winner passes on the same fraction of its value
+                let transfer_value = (top.votes - quota) / top.votes;
+                for (assigned, weight) in assigned.iter().zip(weights.iter_mut()) {
+                    if *assigned == Some(top.option_index) {
+                        *weight *= transfer_value;
+                    }
+                }
+
+                elected.push(top.option_index);
+            } else {
+                eliminated_candidates.insert(bottom.option_index);
+            }
+        }
+
+        ElectionTypeResult::SingleTransferableVoteResult(SingleTransferableVoteResult {
+            winners: elected.iter().map(|i| options[*i].clone()).collect(),
+            seats,
+            quota,
+            total_votes: votes.len(),
+            rounds,
+            bundles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SingleTransferableVoteResult;
+    use crate::tally::{
+        ballot::{self, Ballot},
+        tests::CANDIDATES,
+        ElectionType, ElectionTypeResult,
+    };
+
+    fn count<'a>(
+        candidates: &[&'a str],
+        ballots: &[Ballot],
+        seats: usize,
+    ) -> SingleTransferableVoteResult<&'a str> {
+        match ballot::tally(
+            ElectionType::SingleTransferableVote,
+            candidates,
+            ballots,
+            seats,
+        )
+        .unwrap()
+        {
+            ElectionTypeResult::SingleTransferableVoteResult(result) => result,
+            _ => unreachable!("Single Transferable Vote counts to its own result"),
+        }
+    }
+
+    fn votes_in_round(
+        result: &SingleTransferableVoteResult<&str>,
+        round: usize,
+        option_index: usize,
+    ) -> f64 {
+        result.rounds[round]
+            .vote_tally
+            .iter()
+            .find(|tally| tally.option_index == option_index)
+            .unwrap()
+            .votes
+    }
+
+    #[test]
+    fn transfers_a_surplus_at_a_fraction_of_its_value() {
+        let candidates = [
+            "Oranges",
+            "Pears",
+            "Chocolate",
+            "Strawberries",
+            "Hamburgers",
+        ];
+        let ballots = ballot::ranked(&[
+            (4, &[0]),
+            (2, &[1, 0]),
+            (8, &[2, 3]),
+            (4, &[2, 4]),
+            (1, &[3]),
+            (1, &[4]),
+        ]);
+
+        let result = count(&candidates, &ballots, 3);
+
+        assert_eq!(result.quota, 6.0);
+        assert_eq!(result.winners, vec!["Chocolate", "Oranges", "Strawberries"]);
+        // Chocolate's 6 surplus of 12 sends on half of every one of its ballots
+        assert_eq!(votes_in_round(&result, 1, 3), 5.0);
+    }
+
+    #[test]
+    fn exhausted_ballots_stop_counting() {
+        let ballots = ballot::ranked(&[(4, &[0, 1]), (3, &[0]), (2, &[2, 3]), (1, &[3])]);
+
+        let result = count(&CANDIDATES[..4], &ballots, 2);
+
+        assert_eq!(result.quota, 4.0);
+        // Alice's surplus only reaches Bob as 12/7 of a vote, Dave's ballot runs out when he goes
+        assert_eq!(result.winners, vec!["Alice", "Carol"]);
+        assert!((votes_in_round(&result, 1, 1) - 12.0 / 7.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,135 @@
+use serde::Serialize;
+
+use super::{
+    voting_methods::{fill, vote_bundle, OptionRating, ScoreBallot, VoteBundle},
+    Candidate, ElectionImpl, ElectionTypeResult,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunoffTally {
+    pub option_index: usize,
+    pub votes: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Runoff {
+    pub a: RunoffTally,
+    pub b: RunoffTally,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoreTally {
+    pub option_index: usize,
+    pub score: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StarResult<C> {
+    pub winner: C,
+    pub total_votes: usize,
+    pub runoff: Runoff,
+    pub score_tally: Vec<ScoreTally>,
+    pub bundles: Vec<VoteBundle<ScoreBallot<5>>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Star;
+
+impl ElectionImpl for Star {
+    fn result<C: Candidate>(
+        options: &[C],
+        option_ratings: &Vec<&Vec<OptionRating>>,
+    ) -> ElectionTypeResult<C> {
+        count(options, fill::<ScoreBallot<5>>(option_ratings))
+    }
+}
+
+/// Counts ballots already scored, which can go past 5 when they come from outside the sim.
+pub fn count<C: Candidate>(options: &[C], votes: Vec<ScoreBallot<5>>) -> ElectionTypeResult<C> {
+    let bundles = vote_bundle(&votes);
+
+    let mut score_tally = vec![];
+    for option_index in 0..options.len() {
+        let mut score = 0;
+        for bundle in &bundles {
+            score += bundle.ballot.votes[option_index] * bundle.votes as i32;
+        }
+        score_tally.push(ScoreTally {
+            option_index,
+            score,
+        });
+    }
+
+    score_tally.sort_by(|a, b| a.score.cmp(&b.score).reverse());
+
+    let a = score_tally[0].option_index;
+    let b = score_tally[1].option_index;
+
+    let mut votes_a = 0;
+    let mut votes_b = 0;
+
+    for bundle in &bundles {
+        let a = bundle.ballot.votes[a];
+        let b = bundle.ballot.votes[b];
+
+        if a > b {
+            votes_a += bundle.votes;
+        } else if b > a {
+            votes_b += bundle.votes;
+        }
+    }
+
+    let winner = if votes_a > votes_b { a } else { b };
+
+    ElectionTypeResult::StarResult(StarResult {
+        winner: options[winner].clone(),
+        total_votes: votes.len(),
+        runoff: Runoff {
+            a: RunoffTally {
+                option_index: a,
+                votes: votes_a,
+            },
+            b: RunoffTally {
+                option_index: b,
+                votes: votes_b,
+            },
+        },
+        score_tally,
+        bundles,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{
+        ballot::{self, Ballot},
+        ElectionType,
+    };
+
+    #[test]
+    fn counts_scores_as_given() {
+        // Alice's 10s beat Bob's 9s so she takes the runoff 3-2, squeezed to 5 they would tie
+        let ballots = [
+            vec![
+                Ballot::Score {
+                    scores: vec![10, 9, 0],
+                    max: 10
+                };
+                3
+            ],
+            vec![
+                Ballot::Score {
+                    scores: vec![0, 10, 0],
+                    max: 10
+                };
+                2
+            ],
+        ]
+        .concat();
+
+        let result =
+            ballot::tally(ElectionType::Star, &["Alice", "Bob", "Carol"], &ballots, 1).unwrap();
+
+        assert_eq!(*result.get_winner(), "Alice");
+    }
+}
@@ -1,16 +1,18 @@
-use crate::elections::voting_methods::{get_score_counts, ScoreBallot};
+use serde::Serialize;
+
+use super::voting_methods::{get_score_counts, ScoreBallot};
 
 use super::{
-    election::{ElectionOption, ElectionTypeResult},
     voting_methods::{fill, vote_bundle, OptionRating, ScoreCount, VoteBundle},
+    Candidate, ElectionTypeResult,
 };
 
 type UsualJudgmentBallot = ScoreBallot<6>;
 type UsualJudgmentCount = ScoreCount<6>;
 
-#[derive(Debug, Clone)]
-pub struct UsualJudgmentResult {
-    pub winner: ElectionOption,
+#[derive(Debug, Clone, Serialize)]
+pub struct UsualJudgmentResult<C> {
+    pub winner: C,
     pub total_votes: usize,
     pub bundles: Vec<VoteBundle<UsualJudgmentBallot>>,
     pub counts: Vec<UsualJudgmentCount>,
@@ -36,12 +38,18 @@ fn score_n(count: &UsualJudgmentCount, n: f64) -> f64 {
     deviation
 }
 
-pub fn result(
-    options: &[ElectionOption],
+pub fn result<C: Candidate>(
+    options: &[C],
     option_ratings: &Vec<&Vec<OptionRating>>,
-) -> ElectionTypeResult {
-    let votes = fill::<UsualJudgmentBallot>(option_ratings);
+) -> ElectionTypeResult<C> {
+    count(options, fill::<UsualJudgmentBallot>(option_ratings))
+}
 
+/// Counts ballots already graded, which can go past 6 when they come from outside the sim.
+pub fn count<C: Candidate>(
+    options: &[C],
+    votes: Vec<UsualJudgmentBallot>,
+) -> ElectionTypeResult<C> {
     let bundles = vote_bundle(&votes);
 
     let counts = get_score_counts(&votes);
@@ -102,3 +110,35 @@ pub fn result(
 
     panic!("impossible")
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::{
+        ballot::{self, Ballot},
+        ElectionType,
+    };
+
+    #[test]
+    fn grades_scores_as_given() {
+        // Alice's median is 10 and Bob's is 9, which would both be top grade out of 6
+        let ballots = vec![
+            Ballot::Score {
+                scores: vec![10, 9],
+                max: 10,
+            },
+            Ballot::Score {
+                scores: vec![10, 9],
+                max: 10,
+            },
+            Ballot::Score {
+                scores: vec![8, 9],
+                max: 10,
+            },
+        ];
+
+        let result =
+            ballot::tally(ElectionType::UsualJudgment, &["Alice", "Bob"], &ballots, 1).unwrap();
+
+        assert_eq!(*result.get_winner(), "Alice");
+    }
+}
@@ -1,14 +1,25 @@
 use std::collections::HashMap;
 
-use super::election::{want_level, ElectionOption};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+use super::want_level;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OptionRating {
     pub option_index: usize,
     pub rating: i32,
 }
 
-#[derive(Debug, Clone)]
+impl OptionRating {
+    // Left off a ballot that only ranks some options, below everything it does rank
+    pub const UNRANKED: i32 = i32::MIN;
+
+    pub fn is_ranked(&self) -> bool {
+        self.rating != Self::UNRANKED
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct VoteBundle<T> {
     pub ballot: T,
     pub votes: usize,
@@ -36,37 +47,35 @@ where
     T: std::cmp::PartialEq,
     T: std::clone::Clone,
 {
-    let mut tallies: HashMap<T, usize> = HashMap::new();
+    // Bundles are kept in the order their ballot was first seen so ties sort the same every run
+    let mut indexes: HashMap<T, usize> = HashMap::new();
+    let mut tallies: Vec<VoteBundle<T>> = vec![];
 
     for vote in votes {
-        if tallies.get_mut(vote).is_none() {
-            tallies.insert(vote.clone(), 0);
+        if !indexes.contains_key(vote) {
+            indexes.insert(vote.clone(), tallies.len());
+            tallies.push(VoteBundle {
+                ballot: vote.clone(),
+                votes: 0,
+            });
         }
 
-        let tally = tallies.get_mut(vote).unwrap();
-        *tally += 1;
+        let tally = &mut tallies[indexes[vote]];
+        tally.votes += 1;
     }
 
-    let mut tallies: Vec<_> = tallies
-        .into_iter()
-        .map(|(k, v)| VoteBundle {
-            ballot: k,
-            votes: v,
-        })
-        .collect();
-
     tallies.sort_by(|a, b| b.votes.cmp(&a.votes));
 
     tallies
 }
 
-#[derive(Debug, Clone)]
-pub struct VoteCount {
-    pub option: ElectionOption,
+#[derive(Debug, Clone, Serialize)]
+pub struct VoteCount<C> {
+    pub option: C,
     pub votes: i64,
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize)]
 pub struct SingleOptionBallot {
     pub voted_for: usize,
 }
@@ -80,7 +89,7 @@ impl VotingMethod for SingleOptionBallot {
     }
 }
 
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct MultipleOptionBallot {
     pub voted_for: Vec<usize>,
 }
@@ -100,7 +109,7 @@ impl VotingMethod for MultipleOptionBallot {
     }
 }
 
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct MandatoryPreferentialBallot {
     pub votes: Vec<usize>,
 }
@@ -108,20 +117,24 @@ pub struct MandatoryPreferentialBallot {
 impl VotingMethod for MandatoryPreferentialBallot {
     fn fill(option_ratings: &[OptionRating]) -> Self {
         MandatoryPreferentialBallot {
-            // already in order
-            votes: option_ratings.iter().map(|i| i.option_index).collect(),
+            // already in order, anything unranked is left off so the ballot can run out
+            votes: option_ratings
+                .iter()
+                .filter(|i| i.is_ranked())
+                .map(|i| i.option_index)
+                .collect(),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Serialize)]
 pub enum GoodOkBad {
     Bad,
     Ok,
     Good,
 }
 
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct GoodBadOkBallot {
     pub votes: Vec<GoodOkBad>,
 }
@@ -145,7 +158,7 @@ impl VotingMethod for GoodBadOkBallot {
 
 pub type Score = i32;
 
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, Serialize)]
 pub struct ScoreBallot<const N: usize> {
     pub votes: Vec<Score>,
 }
@@ -222,7 +235,7 @@ where
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ScoreCount<const N: usize> {
     pub option_index: usize,
     pub scores: Vec<Score>,
@@ -255,7 +268,7 @@ impl<const N: usize> ScoreCount<N> {
     }
 
     pub fn percent_above_grade(&self, target_grade: Score) -> f64 {
-        ((target_grade as usize)..N)
+        ((target_grade as usize)..self.tallies.len() - 1)
             .map(|i| self.percent_for_score(i as Score))
             .sum()
     }
@@ -275,12 +288,19 @@ pub fn get_score_counts<const N: usize>(votes: &[ScoreBallot<N>]) -> Vec<ScoreCo
 
     // Get number of candidates
     let num_candidates = votes[0].votes.len();
+    // Scores given outside the sim can go past N
+    let highest = votes
+        .iter()
+        .flat_map(|vote| vote.votes.iter().copied())
+        .max()
+        .unwrap_or(0)
+        .max(N as Score);
 
     let mut result = (0..num_candidates)
         .map(|i| ScoreCount {
             option_index: i,
             scores: Default::default(),
-            tallies: vec![0; N + 1],
+            tallies: vec![0; highest as usize + 1],
         })
         .collect::<Vec<_>>();
 
@@ -300,7 +320,7 @@ pub fn get_score_counts<const N: usize>(votes: &[ScoreBallot<N>]) -> Vec<ScoreCo
     result
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize)]
 pub struct LeastFavoriteSingleOptionBallot {
     pub least_favorite: usize,
 }
@@ -0,0 +1,154 @@
+use std::{fmt, time::Duration};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    money::{Money, Treasury, Wallet},
+    sim_time::SimTime,
+};
+
+pub const COLLECTION_PERIOD: Duration = Duration::from_secs(10);
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, enum_iterator::Sequence, Serialize, Deserialize,
+)]
+pub enum Tax {
+    // A percentage of the wages earned since the last collection
+    Income,
+    // A percentage of everything in someone's wallet each collection
+    Wealth,
+    // The same number of dollars from everyone each collection
+    PerCapita,
+}
+
+impl fmt::Display for Tax {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Tax::Income => write!(f, "income tax"),
+            Tax::Wealth => write!(f, "wealth tax"),
+            Tax::PerCapita => write!(f, "flat tax"),
+        }
+    }
+}
+
+impl Tax {
+    pub fn rate_to_string(&self, rate: u32) -> String {
+        match self {
+            Tax::Income | Tax::Wealth => format!("{}%", rate),
+            Tax::PerCapita => format!("${}", rate),
+        }
+    }
+
+    // The rates put on the ballot, the first is always abolishing it
+    pub fn rates(&self) -> &'static [u32] {
+        match self {
+            Tax::Income => &[0, 5, 10, 20, 30, 50],
+            Tax::Wealth => &[0, 1, 2, 5],
+            Tax::PerCapita => &[0, 5, 10, 20, 40],
+        }
+    }
+
+    /// What someone with `wallet` would owe each collection at `rate`.
+    pub fn bill(&self, rate: u32, wallet: &Wallet) -> Money {
+        match self {
+            Tax::Income => wallet.earned * rate as Money / 100.0,
+            Tax::Wealth => wallet.money * rate as Money / 100.0,
+            Tax::PerCapita => rate as Money,
+        }
+    }
+}
+
+/// The tax rates in force, they only change when an election says so.
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
+pub struct TaxPolicy {
+    pub income: u32,
+    pub wealth: u32,
+    pub per_capita: u32,
+}
+
+impl Default for TaxPolicy {
+    fn default() -> Self {
+        Self {
+            income: 10,
+            wealth: 0,
+            per_capita: 0,
+        }
+    }
+}
+
+impl fmt::Display for TaxPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let taxes = enum_iterator::all::<Tax>()
+            .map(|tax| format!("{} {}", tax, tax.rate_to_string(self.rate(tax))))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", taxes.join(", "))
+    }
+}
+
+impl TaxPolicy {
+    pub fn rate(&self, tax: Tax) -> u32 {
+        match tax {
+            Tax::Income => self.income,
+            Tax::Wealth => self.wealth,
+            Tax::PerCapita => self.per_capita,
+        }
+    }
+
+    pub fn set_rate(&mut self, tax: Tax, rate: u32) {
+        match tax {
+            Tax::Income => self.income = rate,
+            Tax::Wealth => self.wealth = rate,
+            Tax::PerCapita => self.per_capita = rate,
+        }
+    }
+
+    /// Everything someone with `wallet` owes this collection.
+    pub fn bill(&self, wallet: &Wallet) -> Money {
+        enum_iterator::all::<Tax>()
+            .map(|tax| tax.bill(self.rate(tax), wallet))
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
+pub struct TaxCollection {
+    pub timer: Timer,
+    // Over the whole run
+    pub collected: Money,
+}
+
+impl Default for TaxCollection {
+    fn default() -> Self {
+        Self {
+            timer: Timer::new(COLLECTION_PERIOD, TimerMode::Repeating),
+            collected: 0.0,
+        }
+    }
+}
+
+pub fn collect_taxes_system(
+    sim_time: Res<SimTime>,
+    policy: Res<TaxPolicy>,
+    mut collection: ResMut<TaxCollection>,
+    mut treasury: ResMut<Treasury>,
+    mut wallets: Query<&mut Wallet>,
+) {
+    let finished_count = collection
+        .timer
+        .tick(sim_time.delta())
+        .times_finished_this_tick();
+
+    for _ in 0..finished_count {
+        for mut wallet in &mut wallets {
+            // Nobody is pushed into debt, they pay what they can
+            let paid = policy.bill(&wallet).min(wallet.money);
+            wallet.money -= paid;
+            wallet.earned = 0.0;
+
+            treasury.add(paid);
+            collection.collected += paid;
+        }
+    }
+}
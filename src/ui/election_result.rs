@@ -101,12 +101,15 @@ pub fn update_election_result_system(
 
     {
         let title = text.sections.get_mut(1).unwrap();
-        let winner = last_election.results[0].get_winner();
         title.value = format!(
-            "{} {} - Result: {}",
+            "{} {} - Result: {}\nTurnout: {}/{} ({:.0}%)\n{}",
             last_election.election.election_type.to_string(),
             last_election.name,
-            winner.to_string()
+            last_election.results[0].winners_to_string(),
+            last_election.turnout.voted,
+            last_election.turnout.eligible,
+            last_election.turnout.percent(),
+            last_election.construction_to_string()
         );
     }
 
@@ -121,10 +124,17 @@ pub fn update_election_result_system(
             str.append(format!(
                 "{} - {}\n",
                 result.get_type().to_string(),
-                result.get_winner().to_string()
+                result.winners_to_string()
             ));
         }
 
+        if !last_election.criteria.paradoxes.is_empty() {
+            str.append("\nParadoxes\n");
+            for paradox in &last_election.criteria.paradoxes {
+                str.append(format!("{}\n", paradox));
+            }
+        }
+
         other_results.value = str.string().unwrap();
     }
 }
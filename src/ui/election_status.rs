@@ -157,6 +157,7 @@ struct ElectionStatus {
     name: String,
     election_type: String,
     open_since: Duration,
+    open_for: Duration,
     options: Vec<String>,
     votes: usize,
 }
@@ -194,6 +195,7 @@ pub fn update_election_status_system(
             name: name.0.to_string(),
             election_type: election.election_type.to_string(),
             open_since: election.time_open,
+            open_for: election.open_for,
             options: election.options.iter().map(|x| x.to_string()).collect(),
             votes: election.votes.len(),
         })
@@ -232,7 +234,7 @@ pub fn update_election_status_system(
 
         {
             let closes_in_text = election_status.sections.get_mut(1).unwrap();
-            let closes_in = match Duration::from_secs(15).checked_sub(election.open_since) {
+            let closes_in = match election.open_for.checked_sub(election.open_since) {
                 Some(x) => x,
                 None => Duration::ZERO,
             };
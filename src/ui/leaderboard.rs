@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+use crate::{assets, elections::satisfaction::Leaderboard};
+
+pub fn setup(commands: &mut Commands, asset_server: &AssetServer) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(15.0), Val::Percent(50.0)),
+                border: UiRect::all(Val::Px(5.0)),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Percent(85.0),
+                    top: Val::Percent(15.0),
+                    ..default()
+                },
+                ..default()
+            },
+            background_color: Color::hex("71FFFF").unwrap().into(),
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .insert(LeaderboardRootNode)
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        size: Size::width(Val::Percent(100.0)),
+                        padding: UiRect::all(Val::Px(5.0)),
+                        ..default()
+                    },
+                    background_color: Color::hex("BDFFFF").unwrap().into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn(TextBundle {
+                            text: Text::from_sections(vec![
+                                TextSection::new(
+                                    "Voter Satisfaction\n",
+                                    TextStyle {
+                                        font: asset_server.load(assets::DEFAULT_FONT_PATH),
+                                        font_size: 20.0,
+                                        color: Color::BLACK,
+                                    },
+                                ),
+                                TextSection::new(
+                                    "VALUE",
+                                    TextStyle {
+                                        font: asset_server.load(assets::DEFAULT_FONT_PATH),
+                                        font_size: 13.0,
+                                        color: Color::BLACK,
+                                    },
+                                ),
+                            ]),
+                            ..default()
+                        })
+                        .insert(LeaderboardText);
+                });
+        });
+}
+
+#[derive(Debug, Component)]
+pub struct LeaderboardRootNode;
+
+#[derive(Debug, Component)]
+pub struct LeaderboardText;
+
+pub fn update_leaderboard_system(
+    mut commands: Commands,
+    leaderboard: Res<Leaderboard>,
+    root: Query<Entity, With<LeaderboardRootNode>>,
+    mut text: Query<&mut Text, With<LeaderboardText>>,
+) {
+    // Nothing to rank until an election has been held
+    if !leaderboard.is_changed() || leaderboard.counted == 0 {
+        return;
+    }
+
+    if let Some(root) = root.iter().next() {
+        commands.entity(root).insert(Visibility::Visible);
+    }
+
+    let mut text = match text.iter_mut().next() {
+        Some(text) => text,
+        None => return,
+    };
+
+    let mut str = string_builder::Builder::default();
+    str.append(format!(
+        "Mean VSE over {} elections\n\n",
+        leaderboard.counted
+    ));
+    for (rank, entry) in leaderboard.ranked().into_iter().enumerate() {
+        str.append(format!(
+            "{}. {} {:.2}\n",
+            rank + 1,
+            entry.election_type.to_string(),
+            entry.mean_vse()
+        ));
+    }
+
+    text.sections[1].value = str.string().unwrap();
+}
@@ -4,7 +4,9 @@ mod control_buttons;
 pub mod election_result;
 pub mod election_status;
 pub mod info_text;
+pub mod leaderboard;
 pub mod money;
+pub mod polls;
 
 use bevy::prelude::*;
 
@@ -32,6 +34,8 @@ pub fn setup(
     election_status::setup(&mut commands, &asset_server);
     election_result::setup(&mut commands, &asset_server);
     money::setup(&mut commands, &asset_server);
+    polls::setup(&mut commands, &asset_server);
+    leaderboard::setup(&mut commands, &asset_server);
     control_buttons::setup(&mut commands, &asset_server);
 
     state.set(AppState::Running);
@@ -55,6 +59,8 @@ impl Plugin for VotiesUiPlugin {
             button::button_color_system.in_set(UiSet::Normal),
             money::update_election_status_system.in_set(UiSet::Normal),
             control_buttons::show_last_election_button_system.in_set(UiSet::Normal),
+            polls::update_poll_system.in_set(UiSet::Normal),
+            leaderboard::update_leaderboard_system.in_set(UiSet::Normal),
         ))
         .configure_set(UiSet::Normal.run_if(in_state(AppState::Running)));
     }
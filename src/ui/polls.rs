@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+
+use crate::{
+    assets,
+    elections::{election::Election, polling::OpinionPolls},
+};
+
+pub fn setup(commands: &mut Commands, asset_server: &AssetServer) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(25.0), Val::Percent(50.0)),
+                border: UiRect::all(Val::Px(5.0)),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Percent(0.0),
+                    top: Val::Percent(15.0),
+                    ..default()
+                },
+                ..default()
+            },
+            background_color: Color::hex("71FFFF").unwrap().into(),
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .insert(PollRootNode)
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        size: Size::width(Val::Percent(100.0)),
+                        padding: UiRect::all(Val::Px(5.0)),
+                        ..default()
+                    },
+                    background_color: Color::hex("BDFFFF").unwrap().into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn(TextBundle {
+                            text: Text::from_sections(vec![
+                                TextSection::new(
+                                    "Latest Poll\n",
+                                    TextStyle {
+                                        font: asset_server.load(assets::DEFAULT_FONT_PATH),
+                                        font_size: 20.0,
+                                        color: Color::BLACK,
+                                    },
+                                ),
+                                TextSection::new(
+                                    "VALUE",
+                                    TextStyle {
+                                        font: asset_server.load(assets::DEFAULT_FONT_PATH),
+                                        font_size: 13.0,
+                                        color: Color::BLACK,
+                                    },
+                                ),
+                            ]),
+                            ..default()
+                        })
+                        .insert(PollText);
+                });
+        });
+}
+
+#[derive(Debug, Component)]
+pub struct PollRootNode;
+
+#[derive(Debug, Component)]
+pub struct PollText;
+
+fn percent(fraction: f32) -> String {
+    format!("{:.0}%", fraction * 100.0)
+}
+
+pub fn update_poll_system(
+    mut commands: Commands,
+    polls: Res<OpinionPolls>,
+    root_vis: Query<(Entity, &Visibility), With<PollRootNode>>,
+    mut text: Query<&mut Text, With<PollText>>,
+    elections: Query<(Entity, &Election)>,
+) {
+    let (root_entity, root_vis) = match root_vis.iter().next() {
+        Some(root) => root,
+        None => return,
+    };
+
+    // Same election the status panel is showing
+    let poll = elections
+        .iter()
+        .min_by_key(|(_, election)| election.time_open)
+        .and_then(|(entity, election)| {
+            polls
+                .latest
+                .get(&entity)
+                .map(|poll| (election.election_type, poll))
+        });
+
+    let (held_type, poll) = match poll {
+        Some(poll) => poll,
+        None => {
+            if *root_vis != Visibility::Hidden {
+                commands.entity(root_entity).insert(Visibility::Hidden);
+            }
+            return;
+        }
+    };
+    if *root_vis != Visibility::Visible {
+        commands.entity(root_entity).insert(Visibility::Visible);
+    }
+
+    if !polls.is_changed() {
+        return;
+    }
+
+    let mut text = match text.iter_mut().next() {
+        Some(text) => text,
+        None => return,
+    };
+
+    let mut str = string_builder::Builder::default();
+    str.append(format!(
+        "Asked {} of {} at {}s\n\nFirst preferences:\n",
+        poll.asked,
+        poll.population,
+        poll.taken_at.as_secs()
+    ));
+    for share in &poll.first_preferences {
+        str.append(format!(
+            " {} {} ±{}\n",
+            share.option.to_string(),
+            percent(share.share),
+            percent(share.margin_of_error)
+        ));
+    }
+
+    str.append("\nPredicted winners:\n");
+    // The method it's actually being held under goes first
+    let results = poll.result_for(held_type).into_iter().chain(
+        poll.results
+            .iter()
+            .filter(|result| result.election_type != held_type),
+    );
+    for result in results {
+        str.append(format!(
+            " {}: {} ({} ±{})\n",
+            result.election_type.to_string(),
+            result
+                .winners
+                .iter()
+                .map(|winner| winner.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            percent(result.support),
+            percent(result.margin_of_error)
+        ));
+    }
+
+    text.sections[1].value = str.string().unwrap();
+}
@@ -1,14 +1,19 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
+    buildings::workplace::Workplace,
     money::{Money, Treasury},
     sim_time::SimTime,
 };
 
-#[derive(Debug, Clone, Component, Default)]
+#[derive(Debug, Clone, Component, Default, Serialize, Deserialize)]
 pub struct UpkeepCost {
     pub cost_per_second: Money,
     pub upkeep_lapsed: bool,
+    // Taken by the building since upkeep was last paid, it goes toward the upkeep first
+    #[serde(default)]
+    pub income: Money,
 }
 
 impl UpkeepCost {
@@ -16,33 +21,39 @@ impl UpkeepCost {
         Self {
             cost_per_second,
             upkeep_lapsed: false,
+            income: 0.0,
         }
     }
 }
 
-#[derive(Debug, Resource)]
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
 pub struct UpkeepCostTimer(pub Timer);
 
 pub fn upkeep_cost_system(
-    time: Res<Time>,
     sim_time: Res<SimTime>,
     mut upkeep_timer: ResMut<UpkeepCostTimer>,
     mut treasury: ResMut<Treasury>,
-    mut query: Query<&mut UpkeepCost>,
+    mut query: Query<(&mut UpkeepCost, Option<&Workplace>)>,
 ) {
     let finished_count = upkeep_timer
         .0
-        .tick(sim_time.delta(&time))
+        .tick(sim_time.delta())
         .times_finished_this_tick();
 
     if finished_count == 0 {
         return;
     }
 
-    for mut upkeep_cost in &mut query {
-        let amount = upkeep_cost.cost_per_second * finished_count as f64;
+    for (mut upkeep_cost, workplace) in &mut query {
+        let scale = workplace.map_or(1.0, |workplace| workplace.upkeep_scale());
+        let amount = upkeep_cost.cost_per_second * scale * finished_count as f64;
 
-        let updated_value = !treasury.spend(amount);
+        // Whatever the building took beyond its upkeep is profit for the treasury
+        let covered = upkeep_cost.income.min(amount);
+        treasury.add(upkeep_cost.income - covered);
+        upkeep_cost.income = 0.0;
+
+        let updated_value = !treasury.spend(amount - covered);
 
         if updated_value != upkeep_cost.upkeep_lapsed {
             upkeep_cost.upkeep_lapsed = updated_value;
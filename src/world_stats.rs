@@ -1,13 +1,25 @@
-use std::time::Duration;
+use std::{
+    fs::{self, File},
+    io,
+    path::PathBuf,
+    time::Duration,
+};
 
-use bevy::{ecs::world, prelude::*, utils::HashMap};
+use bevy::{
+    ecs::{system::SystemParam, world},
+    prelude::*,
+    utils::HashMap,
+};
+use bevy_enum_filter::prelude::*;
 use num_traits::ToPrimitive;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    buildings::{building, house::House},
+    buildings::{building, farm::Farm, house::House, workplace::Workplace},
     death::DeathReason,
     elections::voter::Voter,
     grave::Grave,
+    hunger::{Food, FoodGroup, Nutrition},
     money,
     sim_time::SimTime,
 };
@@ -28,6 +40,28 @@ impl<T: std::marker::Copy> Default for Stat<T> {
     }
 }
 
+// Serde only derives for arrays up to 32 long so the history goes through a slice
+impl<T: Serialize> Serialize for Stat<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.history.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Stat<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let history = Vec::<Option<T>>::deserialize(deserializer)?;
+        let length = history.len();
+
+        match history.try_into() {
+            Ok(history) => Ok(Self { history }),
+            Err(_) => Err(D::Error::invalid_length(
+                length,
+                &"a stat history of HISTORY_LENGTH values",
+            )),
+        }
+    }
+}
+
 impl<
         T: num_traits::ToPrimitive + std::cmp::PartialOrd + std::default::Default + std::marker::Copy,
     > Stat<T>
@@ -105,7 +139,11 @@ impl<
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "T: Serialize + Eq",
+    deserialize = "T: Deserialize<'de> + Eq"
+))]
 pub struct Count<T: std::hash::Hash> {
     map: HashMap<T, usize>,
 }
@@ -147,15 +185,48 @@ impl<T: std::hash::Hash + std::cmp::Eq + std::clone::Clone> Count<T> {
     }
 }
 
-#[derive(Debug, Resource)]
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
 pub struct WorldStats {
     timer: Timer,
     pub money: Stat<money::Money>,
     pub hole_filled_capacity: Stat<f64>,
     pub buildings: Count<building::BuildingStatus>,
     pub houses_filled: Stat<f32>,
+    // Over the last minute
     pub deaths: Count<DeathReason>,
+    // Between the last two samples
+    #[serde(default)]
+    pub sampled_deaths: Count<DeathReason>,
     pub population: Stat<usize>,
+    // Share of every job going that someone has taken
+    #[serde(default)]
+    pub jobs_filled: Stat<f32>,
+    // Mean food price as a multiple of what it would be with supply and demand even
+    #[serde(default)]
+    pub food_prices: Stat<f64>,
+    // Servings made and spoiled between each sample
+    #[serde(default)]
+    pub food_produced: Stat<usize>,
+    #[serde(default)]
+    pub food_spoiled: Stat<usize>,
+    // Over the whole run
+    #[serde(default)]
+    pub food_spoiled_total: usize,
+    // Share of people whose diet has been unbalanced lately
+    #[serde(default)]
+    pub malnourished: Stat<f32>,
+    // Across everyone's diet together
+    #[serde(default)]
+    pub lacking_food_groups: Vec<FoodGroup>,
+    #[serde(default)]
+    pub excess_food_groups: Vec<FoodGroup>,
+    // Since the last sample
+    #[serde(default)]
+    produced_since_sample: usize,
+    #[serde(default)]
+    spoiled_since_sample: usize,
+    #[serde(default)]
+    last_sample: Duration,
 }
 
 impl WorldStats {
@@ -167,29 +238,79 @@ impl WorldStats {
             buildings: Count::default(),
             houses_filled: Stat::default(),
             deaths: Count::default(),
+            sampled_deaths: Count::default(),
             population: Stat::default(),
+            jobs_filled: Stat::default(),
+            food_prices: Stat::default(),
+            food_produced: Stat::default(),
+            food_spoiled: Stat::default(),
+            food_spoiled_total: 0,
+            malnourished: Stat::default(),
+            lacking_food_groups: vec![],
+            excess_food_groups: vec![],
+            produced_since_sample: 0,
+            spoiled_since_sample: 0,
+            last_sample: Duration::ZERO,
+        }
+    }
+
+    pub fn add_food_produced(&mut self, servings: usize) {
+        self.produced_since_sample += servings;
+    }
+
+    pub fn add_food_spoiled(&mut self, servings: usize) {
+        self.spoiled_since_sample += servings;
+        self.food_spoiled_total += servings;
+    }
+
+    /// Share of the food made lately that went off before anyone ate it.
+    pub fn food_waste(&self) -> f64 {
+        let produced = self.food_produced.average();
+        if produced <= 0.0 {
+            return 0.0;
         }
+
+        self.food_spoiled.average() / produced
     }
 }
 
+// Everything in the town a sample is taken from
+#[derive(SystemParam)]
+pub struct Town<'w, 's> {
+    buildings: Query<'w, 's, &'static building::BuildingStatus>,
+    houses: Query<'w, 's, &'static House>,
+    workplaces: Query<'w, 's, &'static Workplace>,
+    farms: Query<
+        'w,
+        's,
+        (&'static Farm, &'static Food),
+        With<Enum!(building::BuildingStatus::Operational)>,
+    >,
+    diets: Query<'w, 's, &'static Nutrition>,
+    graves: Query<'w, 's, &'static Grave>,
+    voties: Query<'w, 's, &'static Voter>,
+}
+
 pub fn world_stats_update_system(
-    time: Res<Time>,
     sim_time: Res<SimTime>,
     mut world_stats: ResMut<WorldStats>,
     treasury: Res<money::Treasury>,
-    buildings: Query<&building::BuildingStatus>,
-    houses: Query<&House>,
-    graves: Query<&Grave>,
-    voties: Query<&Voter>,
+    town: Town,
 ) {
-    if !world_stats
-        .timer
-        .tick(sim_time.delta(&time))
-        .just_finished()
-    {
+    if !world_stats.timer.tick(sim_time.delta()).just_finished() {
         return;
     }
 
+    let Town {
+        buildings,
+        houses,
+        workplaces,
+        farms,
+        diets,
+        graves,
+        voties,
+    } = town;
+
     world_stats.money.push(treasury.money);
     world_stats
         .hole_filled_capacity
@@ -206,11 +327,59 @@ pub fn world_stats_update_system(
         .houses_filled
         .push(houses_occupants as f32 / houses_capacity as f32);
 
+    // Jobs
+    let mut positions = 0;
+    let mut taken = 0;
+    for workplace in workplaces.iter() {
+        positions += workplace.job.positions();
+        taken += workplace.taken.min(workplace.job.positions());
+    }
+    world_stats
+        .jobs_filled
+        .push(taken as f32 / positions.max(1) as f32);
+
+    // Food prices
+    let mut price_multiples = 0.0;
+    let mut farm_count = 0;
+    for (farm, food) in farms.iter() {
+        price_multiples += farm.price / food.base_price();
+        farm_count += 1;
+    }
+    world_stats
+        .food_prices
+        .push(price_multiples / farm_count.max(1) as f64);
+
+    // Food waste
+    let produced = world_stats.produced_since_sample;
+    let spoiled = world_stats.spoiled_since_sample;
+    world_stats.food_produced.push(produced);
+    world_stats.food_spoiled.push(spoiled);
+    world_stats.produced_since_sample = 0;
+    world_stats.spoiled_since_sample = 0;
+
+    // Diets
+    let mut town_diet = Nutrition::default();
+    let mut malnourished = 0;
+    let mut eaters = 0;
+    for nutrition in diets.iter() {
+        town_diet.add(nutrition);
+        if nutrition.malnourished_for > Duration::ZERO {
+            malnourished += 1;
+        }
+        eaters += 1;
+    }
+    world_stats
+        .malnourished
+        .push(malnourished as f32 / eaters.max(1) as f32);
+    world_stats.lacking_food_groups = town_diet.lacking();
+    world_stats.excess_food_groups = town_diet.excess();
+
     // Graves
     let grave_cutoff = match sim_time.elapsed().checked_sub(Duration::from_secs(60)) {
         Some(val) => val,
         None => Duration::ZERO,
     };
+    let last_sample = world_stats.last_sample;
     for death_reason in enum_iterator::all::<DeathReason>() {
         // SLOW POINT
         world_stats.deaths.update(
@@ -220,7 +389,15 @@ pub fn world_stats_update_system(
                 .filter(|g| g.created > grave_cutoff && g.died_of == death_reason)
                 .count(),
         );
+        world_stats.sampled_deaths.update(
+            &death_reason,
+            graves
+                .iter()
+                .filter(|g| g.created > last_sample && g.died_of == death_reason)
+                .count(),
+        );
     }
+    world_stats.last_sample = sim_time.elapsed();
 
     // Buildings
     for building in enum_iterator::all::<building::BuildingStatus>() {
@@ -233,3 +410,116 @@ pub fn world_stats_update_system(
 
     world_stats.population.push(voties.iter().count());
 }
+
+// Present when every sample should also be written out, the ring buffers only keep the latest few
+#[derive(Debug, Resource)]
+pub struct WorldStatsRecorder {
+    pub path: PathBuf,
+    writer: Option<csv::Writer<File>>,
+    failed: bool,
+}
+
+impl WorldStatsRecorder {
+    /// Returns `None` unless `--record-stats <path>` was passed.
+    pub fn from_args(args: impl Iterator<Item = String>) -> Option<Self> {
+        let mut args = args.skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--record-stats" {
+                let path = args
+                    .next()
+                    .expect("--record-stats requires a path to a csv file");
+                return Some(Self {
+                    path: path.into(),
+                    writer: None,
+                    failed: false,
+                });
+            }
+        }
+
+        None
+    }
+
+    fn header() -> Vec<String> {
+        let mut header = vec![
+            "sim_time".to_string(),
+            "money".to_string(),
+            "hole_filled_capacity".to_string(),
+            "houses_filled".to_string(),
+            "population".to_string(),
+            "jobs_filled".to_string(),
+            "food_prices".to_string(),
+            "food_produced".to_string(),
+            "food_spoiled".to_string(),
+            "malnourished".to_string(),
+        ];
+        for death_reason in enum_iterator::all::<DeathReason>() {
+            header.push(format!("deaths_{:?}", death_reason));
+        }
+        for building in enum_iterator::all::<building::BuildingStatus>() {
+            header.push(format!("buildings_{:?}", building));
+        }
+
+        header
+    }
+
+    fn row(sim_time: &SimTime, world_stats: &WorldStats) -> Vec<String> {
+        let mut row = vec![
+            sim_time.elapsed().as_secs_f64().to_string(),
+            world_stats.money.latest().to_string(),
+            world_stats.hole_filled_capacity.latest().to_string(),
+            world_stats.houses_filled.latest().to_string(),
+            world_stats.population.latest().to_string(),
+            world_stats.jobs_filled.latest().to_string(),
+            world_stats.food_prices.latest().to_string(),
+            world_stats.food_produced.latest().to_string(),
+            world_stats.food_spoiled.latest().to_string(),
+            world_stats.malnourished.latest().to_string(),
+        ];
+        for death_reason in enum_iterator::all::<DeathReason>() {
+            row.push(world_stats.sampled_deaths.get(&death_reason).to_string());
+        }
+        for building in enum_iterator::all::<building::BuildingStatus>() {
+            row.push(world_stats.buildings.get(&building).to_string());
+        }
+
+        row
+    }
+
+    fn record(&mut self, sim_time: &SimTime, world_stats: &WorldStats) -> io::Result<()> {
+        if self.writer.is_none() {
+            if let Some(dir) = self.path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let mut writer = csv::Writer::from_path(&self.path)?;
+            writer.write_record(Self::header())?;
+            self.writer = Some(writer);
+        }
+
+        let writer = self.writer.as_mut().unwrap();
+        writer.write_record(Self::row(sim_time, world_stats))?;
+        // Flushed every sample so a run that is killed still leaves everything up to then
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+pub fn record_world_stats_system(
+    sim_time: Res<SimTime>,
+    world_stats: Res<WorldStats>,
+    mut recorder: ResMut<WorldStatsRecorder>,
+) {
+    // Only when the update system above has just taken a sample
+    if recorder.failed || !world_stats.timer.just_finished() {
+        return;
+    }
+
+    if let Err(err) = recorder.record(&sim_time, &world_stats) {
+        error!(
+            "Unable to record world stats to {}: {}",
+            recorder.path.display(),
+            err
+        );
+        recorder.failed = true;
+    }
+}